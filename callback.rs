@@ -0,0 +1,160 @@
+use crate::{
+	private,
+	sys::{
+		napi_callback_info, napi_env, napi_get_cb_info, napi_get_new_target, napi_status,
+		napi_value,
+	},
+	Env, Error, Result, Undefined, Value,
+};
+use std::{ffi::c_void, mem::MaybeUninit};
+
+pub(crate) type Callback = dyn for<'a> Fn(Env<'a>, CallContext<'a>) -> Result<Value<'a>>;
+
+/// A final parameter of a function or method defined with the macros that collects the remaining arguments.
+pub struct Rest<T>(pub Vec<T>);
+
+/// The receiver and arguments of a call from JS into a Rust callback.
+pub struct CallContext<'a> {
+	env: Env<'a>,
+	info: napi_callback_info,
+	this: Value<'a>,
+	args: Vec<Value<'a>>,
+	data: *mut c_void,
+}
+
+impl<'a> CallContext<'a> {
+	/// # Safety
+	/// `info` must be the `napi_callback_info` passed to the currently running callback.
+	pub unsafe fn from_raw(env: Env<'a>, info: napi_callback_info) -> Result<CallContext<'a>> {
+		let mut argc = 0;
+		let status = napi_get_cb_info(
+			env.raw(),
+			info,
+			&mut argc,
+			std::ptr::null_mut(),
+			std::ptr::null_mut(),
+			std::ptr::null_mut(),
+		);
+		if status != napi_status::napi_ok {
+			return Err(Error::from_last_node_api_error(env.raw(), status));
+		}
+		let mut argv: Vec<napi_value> = vec![std::ptr::null_mut(); argc];
+		let mut this = MaybeUninit::uninit();
+		let mut data = MaybeUninit::uninit();
+		let status = napi_get_cb_info(
+			env.raw(),
+			info,
+			&mut argc,
+			argv.as_mut_ptr(),
+			this.as_mut_ptr(),
+			data.as_mut_ptr(),
+		);
+		if status != napi_status::napi_ok {
+			return Err(Error::from_last_node_api_error(env.raw(), status));
+		}
+		let this = Value::from_raw(env, this.assume_init());
+		let args = argv
+			.into_iter()
+			.map(|arg| Value::from_raw(env, arg))
+			.collect();
+		Ok(CallContext {
+			env,
+			info,
+			this,
+			args,
+			data: data.assume_init(),
+		})
+	}
+
+	pub fn env(&self) -> Env<'a> {
+		self.env
+	}
+
+	pub fn this(&self) -> Value<'a> {
+		self.this
+	}
+
+	pub fn args(&self) -> &[Value<'a>] {
+		&self.args
+	}
+
+	/// Get the argument at `index`, or `undefined` if fewer arguments were passed.
+	pub fn arg(&self, index: usize) -> Result<Value<'a>> {
+		match self.args.get(index) {
+			Some(arg) => Ok(*arg),
+			None => Ok(Undefined::new(self.env)?.value()),
+		}
+	}
+
+	pub fn len(&self) -> usize {
+		self.args.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.args.is_empty()
+	}
+
+	/// Get `new.target`, which is `None` unless the callback was called as a constructor.
+	pub fn new_target(&self) -> Result<Option<Value<'a>>> {
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_get_new_target(self.env.raw(), self.info, result.as_mut_ptr());
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env.raw(), status));
+			}
+			result.assume_init()
+		};
+		if value.is_null() {
+			Ok(None)
+		} else {
+			Ok(Some(Value::from_raw(self.env, value)))
+		}
+	}
+
+	pub(crate) fn data(&self) -> *mut c_void {
+		self.data
+	}
+}
+
+/// Run `f`, throwing a JS exception if it returns an error or panics.
+pub(crate) fn run_callback<'a>(env: Env<'a>, f: impl FnOnce() -> Result<Value<'a>>) -> napi_value {
+	let result = private::catch_unwind(f);
+	let result = match result {
+		Ok(result) => result,
+		Err(payload) => {
+			private::throw_panic(env, payload);
+			return std::ptr::null_mut();
+		}
+	};
+	match result {
+		Ok(value) => value.raw(),
+		Err(error) => {
+			if !env.is_exception_pending() {
+				env.throw(error);
+			}
+			std::ptr::null_mut()
+		}
+	}
+}
+
+/// The `napi_callback` for a boxed `Callback` passed as the callback data.
+pub(crate) unsafe extern "C" fn call_callback(
+	env: napi_env,
+	info: napi_callback_info,
+) -> napi_value {
+	let env = Env::from_raw(env);
+	run_callback(env, || {
+		let context = CallContext::from_raw(env, info)?;
+		let callback = &*(context.data() as *const Box<Callback>);
+		callback(env, context)
+	})
+}
+
+/// The `napi_finalize` for a boxed `Callback` passed as the finalize data.
+pub(crate) unsafe extern "C" fn finalize_callback(
+	_env: napi_env,
+	data: *mut c_void,
+	_hint: *mut c_void,
+) {
+	drop(Box::from_raw(data as *mut Box<Callback>));
+}
@@ -0,0 +1,145 @@
+use crate::{
+	callback::run_callback,
+	property::RawPropertyDescriptors,
+	sys::{
+		napi_add_finalizer, napi_callback_info, napi_define_class, napi_env, napi_status,
+		napi_value,
+	},
+	CallContext, Env, Error, Function, PropertyDescriptor, Result, Value,
+};
+use std::{ffi::c_void, mem::MaybeUninit, os::raw::c_char};
+
+/// Implemented by `#[node_api::class]` for structs that `#[node_api::methods]` exposes to JS as classes.
+pub trait NativeClass: 'static {
+	const NAME: &'static str;
+}
+
+type Constructor<T> = dyn for<'a> Fn(Env<'a>, CallContext<'a>) -> Result<T>;
+
+/// A builder for a JS class whose instances wrap a Rust value of type `T`. Use `Object::unwrap` and `Object::unwrap_mut` on `this` to access the value from methods and accessors.
+pub struct Class<'a, T> {
+	env: Env<'a>,
+	name: std::string::String,
+	constructor: Box<Constructor<T>>,
+	properties: Vec<PropertyDescriptor<'a>>,
+}
+
+impl<'a, T: 'static> Class<'a, T> {
+	/// Start defining a class named `name`. When JS calls `new`, `constructor` creates the Rust value that the new instance wraps.
+	pub fn new<F>(env: Env<'a>, name: &str, constructor: F) -> Class<'a, T>
+	where
+		F: for<'b> Fn(Env<'b>, CallContext<'b>) -> Result<T> + 'static,
+	{
+		Class {
+			env,
+			name: name.to_owned(),
+			constructor: Box::new(constructor),
+			properties: Vec::new(),
+		}
+	}
+
+	pub fn method<F>(mut self, name: &str, f: F) -> Class<'a, T>
+	where
+		F: for<'b> Fn(Env<'b>, CallContext<'b>) -> Result<Value<'b>> + 'static,
+	{
+		let property = PropertyDescriptor::method(name, f)
+			.writable(true)
+			.configurable(true);
+		self.properties.push(property);
+		self
+	}
+
+	pub fn static_method<F>(mut self, name: &str, f: F) -> Class<'a, T>
+	where
+		F: for<'b> Fn(Env<'b>, CallContext<'b>) -> Result<Value<'b>> + 'static,
+	{
+		let property = PropertyDescriptor::method(name, f)
+			.writable(true)
+			.configurable(true)
+			.static_();
+		self.properties.push(property);
+		self
+	}
+
+	/// Add a property to the prototype, such as an accessor created with `PropertyDescriptor::accessor`.
+	pub fn property(mut self, property: impl Into<PropertyDescriptor<'a>>) -> Class<'a, T> {
+		self.properties.push(property.into());
+		self
+	}
+
+	/// Add a property to the constructor.
+	pub fn static_property(mut self, property: impl Into<PropertyDescriptor<'a>>) -> Class<'a, T> {
+		self.properties.push(property.into().static_());
+		self
+	}
+
+	/// Define the class and return its constructor.
+	pub fn define(self) -> Result<Function<'a>> {
+		let env = self.env;
+		let properties = RawPropertyDescriptors::new(&self.properties)?;
+		let constructor: Box<Box<Constructor<T>>> = Box::new(self.constructor);
+		let constructor = Box::into_raw(constructor);
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_define_class(
+				env.raw(),
+				self.name.as_ptr() as *const c_char,
+				self.name.len(),
+				Some(call_constructor::<T>),
+				constructor as *mut c_void,
+				properties.descriptors().len(),
+				properties.descriptors().as_ptr(),
+				result.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				drop(Box::from_raw(constructor));
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			let value = result.assume_init();
+			let status = napi_add_finalizer(
+				env.raw(),
+				value,
+				constructor as *mut c_void,
+				Some(finalize_constructor::<T>),
+				std::ptr::null_mut(),
+				std::ptr::null_mut(),
+			);
+			// The class is already defined and may be called, so the constructor is leaked rather than freed.
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			value
+		};
+		properties.attach(env, value)?;
+		let value = Value::from_raw(env, value);
+		value.as_function()
+	}
+}
+
+unsafe extern "C" fn call_constructor<T: 'static>(
+	env: napi_env,
+	info: napi_callback_info,
+) -> napi_value {
+	let env = Env::from_raw(env);
+	run_callback(env, || {
+		let context = CallContext::from_raw(env, info)?;
+		if context.new_target()?.is_none() {
+			return Err(Error::message(
+				"class constructor cannot be invoked without 'new'",
+			));
+		}
+		let constructor = &*(context.data() as *const Box<Constructor<T>>);
+		let this = context.this();
+		let value = constructor(env, context)?;
+		this.as_object()?.wrap(value)?;
+		Ok(this)
+	})
+}
+
+unsafe extern "C" fn finalize_constructor<T: 'static>(
+	_env: napi_env,
+	data: *mut c_void,
+	_hint: *mut c_void,
+) {
+	drop(Box::from_raw(data as *mut Box<Constructor<T>>));
+}
@@ -1,8 +1,11 @@
 use crate::{
-	Array, ArrayBuffer, BigInt, Boolean, Buffer, DataView, Date, Env, Error, External, Function,
-	Null, Number, Object, Result, String, Symbol, TypedArray, Undefined, Value,
+	env::HandleScope,
+	sys::{napi_create_error, napi_create_range_error, napi_create_type_error, napi_status},
+	Array, ArrayBuffer, BigInt, Boolean, Buffer, DataView, Date, Env, Error, ErrorKind, External,
+	Function, Null, Number, Object, Promise, Result, String, Symbol, TypedArray, Undefined, Value,
 };
-use num::{FromPrimitive, ToPrimitive};
+use num::{FromPrimitive, ToPrimitive, Zero};
+use std::mem::MaybeUninit;
 
 #[allow(clippy::wrong_self_convention, clippy::upper_case_acronyms)]
 pub trait IntoNodeApi<'a>: 'a {
@@ -45,8 +48,8 @@ macro_rules! impl_to_from_for_number_type {
 	($ty:ty) => {
 		impl<'a> IntoNodeApi<'a> for $ty {
 			fn into_node_api(self, env: Env<'a>) -> Result<Value<'a>> {
-				let value =
-					<$ty>::to_f64(&self).ok_or_else(|| Error::message("number out of bounds"))?;
+				let value = <$ty>::to_f64(&self)
+					.ok_or_else(|| Error::range_error("number out of bounds"))?;
 				let number = Number::new(env, value)?;
 				Ok(number.value())
 			}
@@ -55,8 +58,8 @@ macro_rules! impl_to_from_for_number_type {
 			fn from_node_api(value: Value<'a>) -> Result<Self> {
 				let number = value.as_number()?;
 				let value = number.get()?;
-				let value =
-					<$ty>::from_f64(value).ok_or_else(|| Error::message("number out of bounds"))?;
+				let value = <$ty>::from_f64(value)
+					.ok_or_else(|| Error::range_error("number out of bounds"))?;
 				Ok(value)
 			}
 		}
@@ -76,6 +79,141 @@ impl_to_from_for_number_type!(i64);
 impl_to_from_for_number_type!(f32);
 impl_to_from_for_number_type!(f64);
 
+/// Convert an `i64` or `u64` to and from a JS `BigInt` instead of a `Number` to avoid losing precision.
+pub struct AsBigInt<T>(pub T);
+
+impl<'a> IntoNodeApi<'a> for AsBigInt<i64> {
+	fn into_node_api(self, env: Env<'a>) -> Result<Value<'a>> {
+		Ok(BigInt::from_i64(env, self.0)?.value())
+	}
+}
+
+impl<'a> FromNodeAPI<'a> for AsBigInt<i64> {
+	fn from_node_api(value: Value<'a>) -> Result<Self> {
+		let (value, lossless) = value.as_bigint()?.get_i64()?;
+		if !lossless {
+			return Err(Error::range_error("bigint out of bounds"));
+		}
+		Ok(AsBigInt(value))
+	}
+}
+
+impl<'a> IntoNodeApi<'a> for AsBigInt<u64> {
+	fn into_node_api(self, env: Env<'a>) -> Result<Value<'a>> {
+		Ok(BigInt::from_u64(env, self.0)?.value())
+	}
+}
+
+impl<'a> FromNodeAPI<'a> for AsBigInt<u64> {
+	fn from_node_api(value: Value<'a>) -> Result<Self> {
+		let (value, lossless) = value.as_bigint()?.get_u64()?;
+		if !lossless {
+			return Err(Error::range_error("bigint out of bounds"));
+		}
+		Ok(AsBigInt(value))
+	}
+}
+
+fn u128_from_words(words: &[u64]) -> Result<u128> {
+	if words.iter().skip(2).any(|word| *word != 0) {
+		return Err(Error::range_error("bigint out of bounds"));
+	}
+	let low = words.first().copied().unwrap_or(0) as u128;
+	let high = words.get(1).copied().unwrap_or(0) as u128;
+	Ok(low | (high << 64))
+}
+
+impl<'a> IntoNodeApi<'a> for u128 {
+	fn into_node_api(self, env: Env<'a>) -> Result<Value<'a>> {
+		let words = [self as u64, (self >> 64) as u64];
+		Ok(BigInt::from_words(env, false, &words)?.value())
+	}
+}
+
+impl<'a> FromNodeAPI<'a> for u128 {
+	fn from_node_api(value: Value<'a>) -> Result<Self> {
+		let (negative, words) = value.as_bigint()?.get_words()?;
+		let value = u128_from_words(&words)?;
+		if negative && value != 0 {
+			return Err(Error::range_error("bigint out of bounds"));
+		}
+		Ok(value)
+	}
+}
+
+impl<'a> IntoNodeApi<'a> for i128 {
+	fn into_node_api(self, env: Env<'a>) -> Result<Value<'a>> {
+		let magnitude = self.unsigned_abs();
+		let words = [magnitude as u64, (magnitude >> 64) as u64];
+		Ok(BigInt::from_words(env, self < 0, &words)?.value())
+	}
+}
+
+impl<'a> FromNodeAPI<'a> for i128 {
+	fn from_node_api(value: Value<'a>) -> Result<Self> {
+		let (negative, words) = value.as_bigint()?.get_words()?;
+		let magnitude = u128_from_words(&words)?;
+		let value = if negative {
+			if magnitude > i128::MIN.unsigned_abs() {
+				return Err(Error::range_error("bigint out of bounds"));
+			}
+			(magnitude as i128).wrapping_neg()
+		} else {
+			magnitude
+				.to_i128()
+				.ok_or_else(|| Error::range_error("bigint out of bounds"))?
+		};
+		Ok(value)
+	}
+}
+
+fn biguint_from_words(words: &[u64]) -> num::BigUint {
+	let digits = words
+		.iter()
+		.flat_map(|word| std::iter::once(*word as u32).chain(std::iter::once((*word >> 32) as u32)))
+		.collect();
+	num::BigUint::new(digits)
+}
+
+impl<'a> IntoNodeApi<'a> for num::BigUint {
+	fn into_node_api(self, env: Env<'a>) -> Result<Value<'a>> {
+		let words = self.to_u64_digits();
+		Ok(BigInt::from_words(env, false, &words)?.value())
+	}
+}
+
+impl<'a> FromNodeAPI<'a> for num::BigUint {
+	fn from_node_api(value: Value<'a>) -> Result<Self> {
+		let (negative, words) = value.as_bigint()?.get_words()?;
+		let value = biguint_from_words(&words);
+		if negative && !value.is_zero() {
+			return Err(Error::range_error("bigint out of bounds"));
+		}
+		Ok(value)
+	}
+}
+
+impl<'a> IntoNodeApi<'a> for num::BigInt {
+	fn into_node_api(self, env: Env<'a>) -> Result<Value<'a>> {
+		let (sign, words) = self.to_u64_digits();
+		let negative = sign == num::bigint::Sign::Minus;
+		Ok(BigInt::from_words(env, negative, &words)?.value())
+	}
+}
+
+impl<'a> FromNodeAPI<'a> for num::BigInt {
+	fn from_node_api(value: Value<'a>) -> Result<Self> {
+		let (negative, words) = value.as_bigint()?.get_words()?;
+		let sign = if negative {
+			num::bigint::Sign::Minus
+		} else {
+			num::bigint::Sign::Plus
+		};
+		let value = num::BigInt::from_biguint(sign, biguint_from_words(&words));
+		Ok(value)
+	}
+}
+
 impl<'a> IntoNodeApi<'a> for char {
 	fn into_node_api(self, env: Env<'a>) -> Result<Value<'a>> {
 		Ok(String::new(env, &self.to_string())?.value())
@@ -106,6 +244,32 @@ impl<'a> FromNodeAPI<'a> for std::string::String {
 	}
 }
 
+impl<'a> IntoNodeApi<'a> for std::time::SystemTime {
+	fn into_node_api(self, env: Env<'a>) -> Result<Value<'a>> {
+		let value = match self.duration_since(std::time::UNIX_EPOCH) {
+			Ok(duration) => duration.as_secs_f64() * 1000.0,
+			Err(error) => -error.duration().as_secs_f64() * 1000.0,
+		};
+		Ok(Date::new(env, value)?.value())
+	}
+}
+
+impl<'a> FromNodeAPI<'a> for std::time::SystemTime {
+	fn from_node_api(value: Value<'a>) -> Result<Self> {
+		let value = value.as_date()?.get()?;
+		if !value.is_finite() {
+			return Err(Error::range_error("invalid date"));
+		}
+		let duration = std::time::Duration::from_secs_f64(value.abs() / 1000.0);
+		let value = if value >= 0.0 {
+			std::time::UNIX_EPOCH.checked_add(duration)
+		} else {
+			std::time::UNIX_EPOCH.checked_sub(duration)
+		};
+		value.ok_or_else(|| Error::range_error("date out of bounds"))
+	}
+}
+
 impl<'a, T> IntoNodeApi<'a> for Option<T>
 where
 	T: IntoNodeApi<'a>,
@@ -138,6 +302,7 @@ where
 	fn into_node_api(self, env: Env<'a>) -> Result<Value<'a>> {
 		let mut array = Array::new(env)?;
 		for (i, value) in self.into_iter().enumerate() {
+			let _scope = HandleScope::open(env)?;
 			array.set(i, value.into_node_api(env)?)?;
 		}
 		Ok(array.value())
@@ -158,6 +323,61 @@ where
 	}
 }
 
+/// Convert an `Error` into a JS error object of its `ErrorKind`, with its message and code. A `JsException` converts to the original exception.
+impl<'a> IntoNodeApi<'a> for Error {
+	fn into_node_api(self, env: Env<'a>) -> Result<Value<'a>> {
+		self.to_node_api(env)
+	}
+}
+
+impl Error {
+	pub(crate) fn to_node_api<'a>(&self, env: Env<'a>) -> Result<Value<'a>> {
+		if let Error::JsException(exception) = self {
+			if let Some(exception) = exception.value(env)? {
+				return Ok(exception);
+			}
+		}
+		let kind = self.kind();
+		let code = match self.code() {
+			Some(code) => Some(String::new(env, code)?),
+			None => None,
+		};
+		let message = String::new(env, &self.to_string())?;
+		let create = match kind {
+			ErrorKind::Error => napi_create_error,
+			ErrorKind::TypeError => napi_create_type_error,
+			ErrorKind::RangeError => napi_create_range_error,
+			ErrorKind::SyntaxError => {
+				let constructor = env
+					.global()?
+					.as_object()?
+					.get_named("SyntaxError")?
+					.as_function()?;
+				let mut error = constructor.construct(&[message.value()])?.as_object()?;
+				if let Some(code) = code {
+					error.set_named("code", code)?;
+				}
+				return Ok(error.value());
+			}
+		};
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = create(
+				env.raw(),
+				code.map(|code| code.value().raw())
+					.unwrap_or_else(std::ptr::null_mut),
+				message.value().raw(),
+				result.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			result.assume_init()
+		};
+		Ok(Value::from_raw(env, value))
+	}
+}
+
 impl<'a> IntoNodeApi<'a> for Value<'a> {
 	fn into_node_api(self, _env: Env<'a>) -> Result<Value<'a>> {
 		Ok(self)
@@ -236,7 +456,7 @@ impl<'a> IntoNodeApi<'a> for DataView<'a> {
 	}
 }
 
-impl<'a, 'b: 'a> FromNodeAPI<'a> for DataView<'a> {
+impl<'a> FromNodeAPI<'a> for DataView<'a> {
 	fn from_node_api(value: Value<'a>) -> Result<Self> {
 		value.as_dataview()
 	}
@@ -314,6 +534,18 @@ impl<'a> FromNodeAPI<'a> for Object<'a> {
 	}
 }
 
+impl<'a> IntoNodeApi<'a> for Promise<'a> {
+	fn into_node_api(self, _env: Env<'a>) -> Result<Value<'a>> {
+		Ok(self.value())
+	}
+}
+
+impl<'a> FromNodeAPI<'a> for Promise<'a> {
+	fn from_node_api(value: Value<'a>) -> Result<Self> {
+		value.as_promise()
+	}
+}
+
 impl<'a> IntoNodeApi<'a> for String<'a> {
 	fn into_node_api(self, _env: Env<'a>) -> Result<Value<'a>> {
 		Ok(self.value())
@@ -1,6 +1,11 @@
 use crate::{
 	fatal_error,
-	sys::{napi_env, napi_get_global, napi_is_exception_pending, napi_status, napi_throw_error},
+	sys::{
+		napi_close_escapable_handle_scope, napi_close_handle_scope, napi_env, napi_escape_handle,
+		napi_get_global, napi_handle_scope, napi_is_exception_pending,
+		napi_open_escapable_handle_scope, napi_open_handle_scope, napi_status, napi_throw,
+		napi_throw_error,
+	},
 	Error, Result, Value,
 };
 use std::{marker::PhantomData, mem::MaybeUninit};
@@ -17,7 +22,7 @@ impl<'a> Env<'a> {
 		self.0
 	}
 
-	pub fn global(&self) -> Result<Value> {
+	pub fn global(&self) -> Result<Value<'a>> {
 		let value = unsafe {
 			let mut result = MaybeUninit::uninit();
 			let status = napi_get_global(self.raw(), result.as_mut_ptr());
@@ -59,4 +64,115 @@ impl<'a> Env<'a> {
 			}
 		}
 	}
+
+	/// Throw `error` as a JS error of its `ErrorKind`, with its message and code.
+	pub fn throw(&self, error: Error) {
+		let value = match error.to_node_api(*self) {
+			Ok(value) => value,
+			Err(_) => {
+				if !self.is_exception_pending() {
+					self.throw_error(&error.to_string());
+				}
+				return;
+			}
+		};
+		self.throw_value(value);
+	}
+
+	pub(crate) fn throw_value(&self, value: Value) {
+		unsafe {
+			let status = napi_throw(self.raw(), value.raw());
+			if status != napi_status::napi_ok {
+				fatal_error(None, None);
+				unreachable!()
+			}
+		}
+	}
+
+	/// Run `f`, and if it leaves a JS exception pending, clear it and return it as `Error::JsException`.
+	pub fn catch<R, F>(&self, f: F) -> Result<R>
+	where
+		F: FnOnce(Env<'a>) -> Result<R>,
+	{
+		let result = f(*self);
+		if self.is_exception_pending() {
+			return Err(unsafe { Error::from_pending_exception(self.raw()) });
+		}
+		result
+	}
+
+	/// Run `f` in a new handle scope. Values created in the scope are released when it closes, and the lifetime of the `Env` passed to `f` keeps them from being returned. Use this to avoid keeping every intermediate value alive when creating many values in a loop. Create values inside `f` only with the `Env` it is passed. A value created with an `Env` captured from the enclosing scope is also released when the scope closes, but its lifetime does not show that, so it must not be used after `f` returns.
+	pub fn with_scope<R, F>(&self, f: F) -> Result<R>
+	where
+		F: for<'s> FnOnce(Env<'s>) -> Result<R>,
+	{
+		let _scope = HandleScope::open(*self)?;
+		f(Env::from_raw(self.raw()))
+	}
+
+	/// Run `f` in a new escapable handle scope, returning the value `f` returns to the enclosing scope. As with `with_scope`, create values inside `f` only with the `Env` it is passed.
+	pub fn with_escapable_scope<F>(&self, f: F) -> Result<Value<'a>>
+	where
+		F: for<'s> FnOnce(Env<'s>) -> Result<Value<'s>>,
+	{
+		let scope = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_open_escapable_handle_scope(self.raw(), result.as_mut_ptr());
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.raw(), status));
+			}
+			result.assume_init()
+		};
+		let result = f(Env::from_raw(self.raw())).and_then(|value| unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_escape_handle(self.raw(), scope, value.raw(), result.as_mut_ptr());
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.raw(), status));
+			}
+			Ok(Value::from_raw(*self, result.assume_init()))
+		});
+		unsafe {
+			let status = napi_close_escapable_handle_scope(self.raw(), scope);
+			if status != napi_status::napi_ok {
+				fatal_error(None, None);
+				unreachable!()
+			}
+		}
+		result
+	}
+}
+
+/// A handle scope that closes when dropped.
+pub(crate) struct HandleScope {
+	env: napi_env,
+	raw: napi_handle_scope,
+}
+
+impl HandleScope {
+	pub fn open(env: Env) -> Result<HandleScope> {
+		let raw = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_open_handle_scope(env.raw(), result.as_mut_ptr());
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			result.assume_init()
+		};
+		Ok(HandleScope {
+			env: env.raw(),
+			raw,
+		})
+	}
+}
+
+impl Drop for HandleScope {
+	fn drop(&mut self) {
+		unsafe {
+			let status = napi_close_handle_scope(self.env, self.raw);
+			if status != napi_status::napi_ok {
+				fatal_error(None, None);
+				unreachable!()
+			}
+		}
+	}
 }
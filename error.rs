@@ -1,5 +1,9 @@
-use crate::sys::*;
-use std::ffi::CStr;
+use crate::{sys::*, Env, Object, Reference, Value};
+use std::{
+	ffi::CStr,
+	mem::ManuallyDrop,
+	thread::{self, ThreadId},
+};
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
@@ -7,6 +11,58 @@ pub type Result<T, E = Error> = std::result::Result<T, E>;
 pub enum Error {
 	NodeApi(napi_status, String),
 	Message(String),
+	/// A JS exception that was thrown and then caught. Throwing this error to JS rethrows the original value.
+	JsException(JsException),
+	/// An error that is thrown to JS as an error of the given kind, with an optional `code` property.
+	Js {
+		kind: ErrorKind,
+		code: Option<String>,
+		message: String,
+	},
+}
+
+/// A JS exception that was thrown and then caught. Its message is read once, when it is caught. The exception value can only be used on the thread that caught it, and dropping a `JsException` on another thread leaks its reference to the value.
+#[derive(Debug)]
+pub struct JsException {
+	message: String,
+	thread: ThreadId,
+	exception: ManuallyDrop<Reference>,
+}
+
+// The reference is only used and deleted on `thread`.
+unsafe impl Send for JsException {}
+
+unsafe impl Sync for JsException {}
+
+impl JsException {
+	pub fn message(&self) -> &str {
+		&self.message
+	}
+
+	/// Get the exception value, or `None` if this is not the thread that caught it.
+	pub fn value<'a>(&self, env: Env<'a>) -> Result<Option<Value<'a>>> {
+		if thread::current().id() != self.thread {
+			return Ok(None);
+		}
+		self.exception.get(env)
+	}
+}
+
+impl Drop for JsException {
+	fn drop(&mut self) {
+		if thread::current().id() == self.thread {
+			unsafe { ManuallyDrop::drop(&mut self.exception) };
+		}
+	}
+}
+
+/// The JS error constructor used when an `Error` is thrown to JS.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+	Error,
+	TypeError,
+	RangeError,
+	SyntaxError,
 }
 
 impl std::fmt::Display for Error {
@@ -14,6 +70,8 @@ impl std::fmt::Display for Error {
 		let message = match self {
 			Error::NodeApi(_, message) => message,
 			Error::Message(message) => message,
+			Error::JsException(exception) => &exception.message,
+			Error::Js { message, .. } => message,
 		};
 		write!(f, "{}", message)
 	}
@@ -30,9 +88,59 @@ impl Error {
 		Error::Message(message.into())
 	}
 
-	/// Create a `NodeAPIError` value from the most recent Node-API call.
+	pub fn new(kind: ErrorKind, message: impl Into<String>) -> Error {
+		Error::Js {
+			kind,
+			code: None,
+			message: message.into(),
+		}
+	}
+
+	pub fn type_error(message: impl Into<String>) -> Error {
+		Error::new(ErrorKind::TypeError, message)
+	}
+
+	pub fn range_error(message: impl Into<String>) -> Error {
+		Error::new(ErrorKind::RangeError, message)
+	}
+
+	pub fn syntax_error(message: impl Into<String>) -> Error {
+		Error::new(ErrorKind::SyntaxError, message)
+	}
+
+	/// Set the `code` property of the JS error this is thrown as.
+	pub fn with_code(self, code: impl Into<String>) -> Error {
+		let (kind, message) = match self {
+			Error::Js { kind, message, .. } => (kind, message),
+			error => (ErrorKind::Error, error.to_string()),
+		};
+		Error::Js {
+			kind,
+			code: Some(code.into()),
+			message,
+		}
+	}
+
+	pub fn kind(&self) -> ErrorKind {
+		match self {
+			Error::Js { kind, .. } => *kind,
+			_ => ErrorKind::Error,
+		}
+	}
+
+	pub fn code(&self) -> Option<&str> {
+		match self {
+			Error::Js { code, .. } => code.as_deref(),
+			_ => None,
+		}
+	}
+
+	/// Create a `NodeAPIError` value from the most recent Node-API call. If the call failed because a JS exception is pending, the exception is cleared and returned as `JsException`.
 	/// # Safety
 	pub unsafe fn from_last_node_api_error(env: napi_env, status: napi_status) -> Error {
+		if status == napi_status::napi_pending_exception {
+			return Error::from_pending_exception(env);
+		}
 		let mut error_info = std::mem::MaybeUninit::uninit();
 		let last_error_status = napi_get_last_error_info(env, error_info.as_mut_ptr());
 		if last_error_status != napi_status::napi_ok {
@@ -55,4 +163,65 @@ impl Error {
 		let message = message.to_owned();
 		Error::NodeApi(status, message)
 	}
+
+	/// Clear the pending JS exception and create a `JsException` value that holds a reference to it and its message.
+	/// # Safety
+	pub unsafe fn from_pending_exception(env: napi_env) -> Error {
+		let mut exception = std::mem::MaybeUninit::uninit();
+		let status = napi_get_and_clear_last_exception(env, exception.as_mut_ptr());
+		if status != napi_status::napi_ok {
+			napi_fatal_error(std::ptr::null(), 0, std::ptr::null(), 0);
+			unreachable!()
+		}
+		let env = Env::from_raw(env);
+		let exception = Value::from_raw(env, exception.assume_init());
+		let message = exception_message(env, exception)
+			.unwrap_or_else(|| "an exception was thrown".to_owned());
+		match Reference::new(exception) {
+			Ok(exception) => Error::JsException(JsException {
+				message,
+				thread: thread::current().id(),
+				exception: ManuallyDrop::new(exception),
+			}),
+			Err(error) => error,
+		}
+	}
+}
+
+/// Get the `message` property of an exception, or convert it to a string if it has none. Any exception thrown while doing so is cleared.
+fn exception_message(env: Env, exception: Value) -> Option<String> {
+	if env.is_exception_pending() {
+		return None;
+	}
+	let message = exception_message_inner(exception);
+	if env.is_exception_pending() {
+		unsafe {
+			let mut exception = std::mem::MaybeUninit::uninit();
+			napi_get_and_clear_last_exception(env.raw(), exception.as_mut_ptr());
+		}
+	}
+	message.ok()
+}
+
+fn exception_message_inner(exception: Value) -> Result<String> {
+	if exception.is_object()? {
+		let message =
+			Object::from_value(exception).get(crate::String::new(exception.env(), "message")?)?;
+		if message.is_string()? {
+			return message.as_string()?.get();
+		}
+	}
+	let message = unsafe {
+		let mut result = std::mem::MaybeUninit::uninit();
+		let status =
+			napi_coerce_to_string(exception.env().raw(), exception.raw(), result.as_mut_ptr());
+		if status != napi_status::napi_ok {
+			return Err(Error::node_api(
+				status,
+				"could not convert exception to string",
+			));
+		}
+		result.assume_init()
+	};
+	Value::from_raw(exception.env(), message).as_string()?.get()
 }
@@ -0,0 +1,69 @@
+use crate::{Error, Result};
+use std::{
+	future::Future,
+	pin::Pin,
+	sync::{mpsc, Arc, Mutex, OnceLock},
+	task::{Context, Wake, Waker},
+};
+
+/// A future passed to the spawner set with `set_future_spawner`.
+pub type SpawnedFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+type Spawner = dyn Fn(SpawnedFuture) + Send + Sync;
+
+static SPAWNER: OnceLock<Box<Spawner>> = OnceLock::new();
+
+/// Set the function that runs the futures of async functions wrapped with `#[node_api::function]`. By default they run on a single shared thread that polls them without an I/O reactor, so futures that need a runtime such as tokio must be spawned onto it here, for example with `set_future_spawner(move |future| { handle.spawn(future); })`. This fails if a spawner was already set or an async function has already been called.
+pub fn set_future_spawner<F>(spawner: F) -> Result<()>
+where
+	F: Fn(SpawnedFuture) + Send + Sync + 'static,
+{
+	SPAWNER
+		.set(Box::new(spawner))
+		.map_err(|_| Error::message("a future spawner has already been set"))
+}
+
+/// Run `future` with the spawner set with `set_future_spawner`, or on the shared executor thread if none was set.
+pub(crate) fn spawn(future: impl Future<Output = ()> + Send + 'static) {
+	let spawner = SPAWNER.get_or_init(|| Box::new(spawn_on_executor));
+	spawner(Box::pin(future));
+}
+
+struct Task {
+	future: Mutex<Option<SpawnedFuture>>,
+	sender: mpsc::Sender<Arc<Task>>,
+}
+
+impl Wake for Task {
+	fn wake(self: Arc<Self>) {
+		self.sender.send(self.clone()).ok();
+	}
+}
+
+fn spawn_on_executor(future: SpawnedFuture) {
+	static SENDER: OnceLock<mpsc::Sender<Arc<Task>>> = OnceLock::new();
+	let sender = SENDER.get_or_init(|| {
+		let (sender, receiver) = mpsc::channel::<Arc<Task>>();
+		std::thread::Builder::new()
+			.name("node_api executor".to_owned())
+			.spawn(move || {
+				for task in receiver {
+					let mut future = task.future.lock().unwrap();
+					if let Some(mut pending) = future.take() {
+						let waker = Waker::from(task.clone());
+						let mut context = Context::from_waker(&waker);
+						if pending.as_mut().poll(&mut context).is_pending() {
+							future.replace(pending);
+						}
+					}
+				}
+			})
+			.unwrap();
+		sender
+	});
+	let task = Arc::new(Task {
+		future: Mutex::new(Some(future)),
+		sender: sender.clone(),
+	});
+	sender.send(task).ok();
+}
@@ -1,22 +1,45 @@
 use std::os::raw::c_char;
 
+mod callback;
+mod class;
 mod convert;
 mod env;
 mod error;
+mod executor;
+#[doc(hidden)]
+pub mod private;
+mod property;
+mod reference;
 #[cfg(feature = "serde")]
 mod serde;
+mod task;
+mod threadsafe_function;
 mod value;
 
 pub use self::{
-	convert::{FromNodeAPI, IntoNodeApi},
+	callback::{CallContext, Rest},
+	class::{Class, NativeClass},
+	convert::{AsBigInt, FromNodeAPI, IntoNodeApi},
 	env::Env,
-	error::{Error, Result},
+	error::{Error, ErrorKind, JsException, Result},
+	executor::{set_future_spawner, SpawnedFuture},
+	property::{AccessorDescriptor, PropertyDescriptor, PropertyKey},
+	reference::Reference,
+	task::AsyncTask,
+	threadsafe_function::{
+		ThreadsafeFunction, ThreadsafeFunctionCallMode, ThreadsafeFunctionError,
+	},
 	value::{
-		array::ArrayIterator, Array, ArrayBuffer, BigInt, Boolean, Buffer, DataView, Date,
-		External, Function, Null, Number, Object, String, Symbol, TypedArray, Undefined, Value,
+		array::ArrayIterator,
+		dataview::DataViewInfo,
+		object::{KeyCollectionMode, KeyConversion, KeyFilter},
+		promise::Deferred,
+		typedarray::{TypedArrayElement, TypedArrayInfo, TypedArrayType},
+		Array, ArrayBuffer, BigInt, Boolean, Buffer, DataView, Date, External, Function, Null,
+		Number, Object, Promise, String, Symbol, TypedArray, Undefined, Value,
 	},
 };
-pub use node_api_macro::{function, init};
+pub use node_api_macro::{class, function, init, methods};
 pub use node_api_system as sys;
 
 pub fn fatal_error(location: Option<&str>, message: Option<&str>) {
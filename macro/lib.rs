@@ -10,16 +10,17 @@ pub fn init(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 
 fn init_impl(input: proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
 	let input: syn::Ident = syn::parse2(input)?;
+	let into_error = into_error(quote! { error });
 	let code = quote! {
 		#[no_mangle]
 		pub unsafe extern "C" fn napi_register_module_v1(env: node_api::sys::napi_env, exports: node_api::sys::napi_value) -> node_api::sys::napi_value {
 			let env = node_api::Env::from_raw(env);
 			let exports = node_api::Value::from_raw(env, exports);
-			let result = std::panic::catch_unwind(|| #input(env, exports));
+			let result = node_api::private::catch_unwind(|| #input(env, exports));
 			let result = match result {
 				Ok(result) => result,
-				Err(panic_info) => {
-					env.throw_error("A panic occurred.");
+				Err(payload) => {
+					node_api::private::throw_panic(env, payload);
 					return std::ptr::null_mut();
 				},
 			};
@@ -27,7 +28,7 @@ fn init_impl(input: proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenS
 				Ok(exports) => exports,
 				Err(error) => {
 					if !env.is_exception_pending() {
-						env.throw_error(&format!("{}", error));
+						env.throw(#into_error);
 					}
 					return std::ptr::null_mut();
 				}
@@ -38,6 +39,13 @@ fn init_impl(input: proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenS
 	Ok(code)
 }
 
+/// Wrap a Rust function so it can be passed to `Function::new` or used as a module export.
+///
+/// A synchronous function takes an `Env` as its first parameter, followed by its JS arguments. Each argument is converted with `FromNodeAPI`, and the return value with `IntoNodeApi`.
+///
+/// An `async fn` returns a promise. Its future runs off the JS thread with the spawner set by `node_api::set_future_spawner`, so it cannot take an `Env` and its parameters are only the JS arguments. Its arguments and output must be `Send`.
+///
+/// A parameter of type `Option<T>` is optional, a parameter marked `#[default = expr]` uses `expr` when the argument is missing or `undefined`, and a final parameter of type `Rest<T>` or `Vec<Value>` collects the remaining arguments. A final `Vec<Value>` therefore no longer accepts a JS array as its argument, while a `Vec<Value>` in any other position still does.
 #[proc_macro_attribute]
 pub fn function(
 	_attr: proc_macro::TokenStream,
@@ -52,74 +60,101 @@ fn function_impl(input: proc_macro2::TokenStream) -> syn::Result<proc_macro2::To
 	let input: syn::ItemFn = syn::parse2(input)?;
 	let visibility = &input.vis;
 	let ident = &input.sig.ident;
-	let impl_inputs = input.sig.inputs.iter().skip(1);
+	let is_async = input.sig.asyncness.is_some();
+	if is_async {
+		let env_arg = input
+			.sig
+			.inputs
+			.iter()
+			.find(|input| matches!(input, syn::FnArg::Typed(arg) if is_type(&arg.ty, "Env")));
+		if let Some(env_arg) = env_arg {
+			return Err(syn::Error::new(
+				env_arg.span(),
+				"an async fn runs off the JS thread, so it cannot take an `Env`",
+			));
+		}
+	}
+	let skip = if is_async { 0 } else { 1 };
+	let mut impl_sig = input.sig.clone();
+	strip_default_attrs(impl_sig.inputs.iter_mut());
+	let impl_inputs = impl_sig.inputs.iter().skip(skip);
 	let impl_output = &input.sig.output;
 	let impl_block = &input.block;
-	let args = input
-		.sig
-		.inputs
-		.iter()
-		.skip(1)
-		.map(|input| {
-			let input = match input {
-				syn::FnArg::Typed(arg) => arg,
-				syn::FnArg::Receiver(_) => {
-					return Err(syn::Error::new(
-						input.span(),
-						"receiver arg is not allowed here",
-					))
-				}
-			};
-			let ident = match &*input.pat {
-				syn::Pat::Ident(pat_ident) => &pat_ident.ident,
-				_ => return Err(syn::Error::new(input.pat.span(), "invalid pattern")),
-			};
-			Ok(ident)
-		})
-		.collect::<syn::Result<Vec<_>>>()?;
+	let args = typed_args(input.sig.inputs.iter().skip(skip))?;
 	let args_count = args.len();
-	let from_node_api_statements = args
-		.iter()
-		.enumerate()
-		.map(|(i, ident)| {
-			let code = quote! {
-				let #ident = argv[#i];
-				let #ident = node_api::Value::from_raw(env, #ident);
-				let #ident = node_api::FromNodeAPI::from_node_api(#ident)?;
-			};
-			Ok(code)
-		})
-		.collect::<syn::Result<Vec<_>>>()?;
+	let from_node_api_statements = from_node_api_statements(
+		&args,
+		quote! { argc },
+		|i| quote! { argv[#i] },
+		|i| quote! { argv[..argc].iter().copied().skip(#i) },
+	);
+	let args = args.iter().map(|arg| arg.ident).collect::<Vec<_>>();
+	let into_error = into_error(quote! { error });
+	let (function_impl, call_statement) = if is_async {
+		(
+			quote! {
+				async fn function_impl(#(#impl_inputs),*) #impl_output #impl_block
+			},
+			quote! {
+				let output = node_api::private::spawn_future(env, async move {
+					function_impl(#(#args),*).await.map_err(|error| #into_error)
+				})?;
+			},
+		)
+	} else {
+		(
+			quote! {
+				fn function_impl<'a>(env: node_api::Env<'a>, #(#impl_inputs),*) #impl_output #impl_block
+			},
+			quote! {
+				let output = function_impl(env, #(#args),*).map_err(|error| #into_error)?;
+				let output = node_api::IntoNodeApi::into_node_api(output, env)?;
+			},
+		)
+	};
 	let code = quote! {
 		#visibility unsafe extern "C" fn #ident(env: node_api::sys::napi_env, info: node_api::sys::napi_callback_info) -> node_api::sys::napi_value {
-			fn function_impl<'a>(env: node_api::Env<'a>, #(#impl_inputs),*) #impl_output #impl_block
+			#function_impl
 			let env = node_api::Env::from_raw(env);
-			let result = std::panic::catch_unwind(|| -> node_api::Result<_> {
-				let mut argc = #args_count;
-				let mut argv: [node_api::sys::napi_value; #args_count] = [std::ptr::null_mut(); #args_count];
+			let result = node_api::private::catch_unwind(|| -> node_api::Result<_> {
+				let mut argc = 0;
 				let status = node_api::sys::napi_get_cb_info(
 					env.raw(),
 					info,
 					&mut argc as *mut usize,
-					argv.as_mut_ptr() as *mut node_api::sys::napi_value,
+					std::ptr::null_mut(),
 					std::ptr::null_mut(),
 					std::ptr::null_mut()
 				);
 				if status != node_api::sys::napi_status::napi_ok {
 					return Err(node_api::Error::from_last_node_api_error(env.raw(), status).into());
 				}
-				if argc != #args_count {
-					return Err(node_api::Error::message("invalid number of arguments").into());
+				// Node-API fills the slots past argc with undefined.
+				let mut argv_len = std::cmp::max(argc, #args_count);
+				let mut argv: Vec<node_api::sys::napi_value> = vec![std::ptr::null_mut(); argv_len];
+				let status = node_api::sys::napi_get_cb_info(
+					env.raw(),
+					info,
+					&mut argv_len as *mut usize,
+					argv.as_mut_ptr(),
+					std::ptr::null_mut(),
+					std::ptr::null_mut()
+				);
+				if status != node_api::sys::napi_status::napi_ok {
+					return Err(node_api::Error::from_last_node_api_error(env.raw(), status).into());
 				}
+				let argv = argv
+					.into_iter()
+					.map(|value| node_api::Value::from_raw(env, value))
+					.collect::<Vec<_>>();
 				#(#from_node_api_statements)*
-				let output = function_impl(env, #(#args),*).map_err(|error| node_api::Error::message(error.to_string()))?;
-				let output = node_api::IntoNodeApi::into_node_api(output, env)?;
+				#call_statement
 				Ok(output)
 			});
 			let result = match result {
 				Ok(result) => result,
-				Err(_) => {
-					env.throw_error("A panic occurred.");
+				Err(payload) => {
+					node_api::private::throw_panic(env, payload);
 					return std::ptr::null_mut();
 				},
 			};
@@ -127,7 +162,7 @@ fn function_impl(input: proc_macro2::TokenStream) -> syn::Result<proc_macro2::To
 				Ok(output) => output,
 				Err(error) => {
 					if !env.is_exception_pending() {
-						env.throw_error(&format!("{}", error));
+						env.throw(error);
 					}
 					return std::ptr::null_mut();
 				}
@@ -137,3 +172,547 @@ fn function_impl(input: proc_macro2::TokenStream) -> syn::Result<proc_macro2::To
 	};
 	Ok(code)
 }
+
+/// Convert an error returned from a user function into a `node_api::Error`, keeping its kind and code if it already is one.
+fn into_error(error: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+	quote! {
+		{
+			#[allow(unused_imports)]
+			use node_api::private::{IntoMessageError as _, IntoNodeApiError as _};
+			node_api::private::ErrorConverter(#error).into_error()
+		}
+	}
+}
+
+struct Arg<'a> {
+	ident: &'a syn::Ident,
+	ty: &'a syn::Type,
+	kind: ArgKind,
+}
+
+enum ArgKind {
+	Required,
+	/// An `Option<T>`, which is `None` when the argument is omitted.
+	Optional,
+	/// A parameter with a `#[default = expr]` attribute, which is `expr` when the argument is omitted or `undefined`.
+	Default(Box<syn::Expr>),
+	/// A final `Rest<T>` or `Vec<Value>`, which collects the remaining arguments. A `Vec<Value>` in any other position is converted from a JS array.
+	Rest {
+		wrap: bool,
+	},
+}
+
+fn typed_args<'a>(inputs: impl Iterator<Item = &'a syn::FnArg>) -> syn::Result<Vec<Arg<'a>>> {
+	let inputs = inputs.collect::<Vec<_>>();
+	let last = inputs.len().saturating_sub(1);
+	let args = inputs
+		.into_iter()
+		.enumerate()
+		.map(|(index, input)| {
+			let input = match input {
+				syn::FnArg::Typed(arg) => arg,
+				syn::FnArg::Receiver(_) => {
+					return Err(syn::Error::new(
+						input.span(),
+						"receiver arg is not allowed here",
+					))
+				}
+			};
+			let ident = match &*input.pat {
+				syn::Pat::Ident(pat_ident) => &pat_ident.ident,
+				_ => return Err(syn::Error::new(input.pat.span(), "invalid pattern")),
+			};
+			let default = input
+				.attrs
+				.iter()
+				.find(|attr| attr.path.is_ident("default"))
+				.map(|attr| {
+					let parser = |input: syn::parse::ParseStream| {
+						input.parse::<syn::Token![=]>()?;
+						input.parse::<syn::Expr>()
+					};
+					syn::parse::Parser::parse2(parser, attr.tokens.clone())
+				})
+				.transpose()?;
+			let kind = if let Some(default) = default {
+				ArgKind::Default(Box::new(default))
+			} else if is_type(&input.ty, "Rest") {
+				ArgKind::Rest { wrap: true }
+			} else if index == last && is_vec_value_type(&input.ty) {
+				ArgKind::Rest { wrap: false }
+			} else if is_type(&input.ty, "Option") {
+				ArgKind::Optional
+			} else {
+				ArgKind::Required
+			};
+			Ok(Arg {
+				ident,
+				ty: &input.ty,
+				kind,
+			})
+		})
+		.collect::<syn::Result<Vec<_>>>()?;
+	if let Some(arg) = args
+		.iter()
+		.rev()
+		.skip(1)
+		.find(|arg| matches!(arg.kind, ArgKind::Rest { .. }))
+	{
+		return Err(syn::Error::new(
+			arg.ty.span(),
+			"a rest parameter must be the last parameter",
+		));
+	}
+	Ok(args)
+}
+
+/// Remove the `#[default = expr]` attributes from parameters.
+fn strip_default_attrs<'a>(inputs: impl Iterator<Item = &'a mut syn::FnArg>) {
+	for input in inputs {
+		if let syn::FnArg::Typed(input) = input {
+			input.attrs.retain(|attr| !attr.path.is_ident("default"));
+		}
+	}
+}
+
+fn type_ident(ty: &syn::Type) -> Option<&syn::Ident> {
+	match ty {
+		syn::Type::Path(type_path) => type_path.path.segments.last().map(|segment| &segment.ident),
+		_ => None,
+	}
+}
+
+fn is_type(ty: &syn::Type, name: &str) -> bool {
+	matches!(type_ident(ty), Some(ident) if ident == name)
+}
+
+fn is_vec_value_type(ty: &syn::Type) -> bool {
+	is_type(ty, "Vec") && matches!(first_type_arg(ty), Some(ty) if is_type(ty, "Value"))
+}
+
+/// Get the first generic type argument of a path type, such as `T` in `Rest<T>`.
+fn first_type_arg(ty: &syn::Type) -> Option<&syn::Type> {
+	let segment = match ty {
+		syn::Type::Path(type_path) => type_path.path.segments.last()?,
+		_ => return None,
+	};
+	match &segment.arguments {
+		syn::PathArguments::AngleBracketed(arguments) => {
+			arguments.args.iter().find_map(|argument| match argument {
+				syn::GenericArgument::Type(ty) => Some(ty),
+				_ => None,
+			})
+		}
+		_ => None,
+	}
+}
+
+/// Generate the statements that convert the JS arguments to `args`. `argc` is the number of arguments passed, `value(i)` is the argument at `i`, which must be `undefined` if it was omitted, and `rest(i)` is an iterator over the arguments starting at `i`.
+fn from_node_api_statements(
+	args: &[Arg],
+	argc: proc_macro2::TokenStream,
+	value: impl Fn(usize) -> proc_macro2::TokenStream,
+	rest: impl Fn(usize) -> proc_macro2::TokenStream,
+) -> Vec<proc_macro2::TokenStream> {
+	args.iter()
+		.enumerate()
+		.map(|(i, arg)| {
+			let ident = arg.ident;
+			let name = ident.to_string();
+			// For a rest parameter, each argument is converted to the element type.
+			let ty = match arg.kind {
+				ArgKind::Rest { .. } => first_type_arg(arg.ty).unwrap_or(arg.ty),
+				_ => arg.ty,
+			};
+			let ty = type_name(ty);
+			let convert = |value: proc_macro2::TokenStream, index: proc_macro2::TokenStream| {
+				quote! {
+					match node_api::FromNodeAPI::from_node_api(#value) {
+						Ok(value) => value,
+						Err(error) => return Err(node_api::private::invalid_arg_error(#value, #index, #name, #ty, error)),
+					}
+				}
+			};
+			match &arg.kind {
+				ArgKind::Required => {
+					let value = value(i);
+					let convert = convert(quote! { #ident }, quote! { #i });
+					quote! {
+						node_api::private::require_arg(#argc, #i, #name)?;
+						let #ident = #value;
+						let #ident = #convert;
+					}
+				}
+				ArgKind::Optional => {
+					let value = value(i);
+					let convert = convert(quote! { #ident }, quote! { #i });
+					quote! {
+						let #ident = #value;
+						let #ident = #convert;
+					}
+				}
+				ArgKind::Default(default) => {
+					let value = value(i);
+					let arg_ty = arg.ty;
+					let convert = convert(quote! { #ident }, quote! { #i });
+					quote! {
+						let #ident = #value;
+						let #ident: #arg_ty = if #ident.is_undefined()? {
+							#default
+						} else {
+							#convert
+						};
+					}
+				}
+				ArgKind::Rest { wrap } => {
+					let rest = rest(i);
+					let convert = convert(quote! { value }, quote! { i });
+					let wrap = if *wrap {
+						quote! { node_api::Rest(values) }
+					} else {
+						quote! { values }
+					};
+					quote! {
+						let #ident = {
+							let mut values = Vec::new();
+							for (i, value) in #rest.enumerate() {
+								let i = #i + i;
+								values.push(#convert);
+							}
+							#wrap
+						};
+					}
+				}
+			}
+		})
+		.collect()
+}
+
+/// Format a type the way it is written in source, for use in error messages.
+fn type_name(ty: &syn::Type) -> String {
+	quote!(#ty)
+		.to_string()
+		.replace(" :: ", "::")
+		.replace(":: ", "::")
+		.replace(" < ", "<")
+		.replace(" <", "<")
+		.replace("< ", "<")
+		.replace(" >", ">")
+		.replace(" ,", ",")
+		.replace("& ", "&")
+}
+
+/// Mark a struct as a native class. This implements `NativeClass` with the struct's name as the JS class name. Use `#[node_api::methods]` on an impl block of the struct to expose its methods.
+///
+/// Generic structs are not supported.
+#[proc_macro_attribute]
+pub fn class(
+	_attr: proc_macro::TokenStream,
+	input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+	class_impl(input.into())
+		.unwrap_or_else(|e| e.to_compile_error())
+		.into()
+}
+
+fn class_impl(input: proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+	let input: syn::ItemStruct = syn::parse2(input)?;
+	if !input.generics.params.is_empty() {
+		return Err(syn::Error::new(
+			input.generics.span(),
+			"generic classes are not supported",
+		));
+	}
+	let ident = &input.ident;
+	let name = ident.to_string();
+	let code = quote! {
+		#input
+		impl node_api::NativeClass for #ident {
+			const NAME: &'static str = #name;
+		}
+	};
+	Ok(code)
+}
+
+/// Expose the methods in an inherent impl block of a `#[node_api::class]` struct to JS. This generates `pub fn define_class(env: Env) -> Result<Function>`, which defines the class and returns its constructor.
+///
+/// - A method marked `#[constructor]` creates the value each instance wraps. It must not take a receiver. Without a constructor, `new` throws.
+/// - A method marked `#[getter]` or `#[setter]` defines an accessor property named after the method, and must take `&self` or `&mut self`. The `set_` prefix of a setter's name is removed, so `fn set_count` is the setter for `count`.
+/// - Any other `pub` method is exported as a method, or as a static method if it has no receiver.
+/// - Methods that are not `pub` and have none of these attributes are not exported.
+///
+/// Parameters of type `Env` receive the env, and the remaining parameters are converted from the JS arguments as with `#[node_api::function]`. Every method must return a `Result`.
+#[proc_macro_attribute]
+pub fn methods(
+	_attr: proc_macro::TokenStream,
+	input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+	methods_impl(input.into())
+		.unwrap_or_else(|e| e.to_compile_error())
+		.into()
+}
+
+enum MethodKind {
+	Constructor,
+	Getter,
+	Setter,
+	Method,
+}
+
+enum MethodReceiver {
+	None,
+	Ref,
+	Mut,
+}
+
+struct Accessor {
+	name: String,
+	getter: Option<proc_macro2::TokenStream>,
+	setter: Option<proc_macro2::TokenStream>,
+}
+
+fn methods_impl(input: proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+	let mut input: syn::ItemImpl = syn::parse2(input)?;
+	if !input.generics.params.is_empty() {
+		return Err(syn::Error::new(
+			input.generics.span(),
+			"generic classes are not supported",
+		));
+	}
+	if let Some((_, path, _)) = &input.trait_ {
+		return Err(syn::Error::new(
+			path.span(),
+			"methods must be in an inherent impl block",
+		));
+	}
+	let self_ty = input.self_ty.clone();
+	let mut constructor = None;
+	let mut methods = Vec::new();
+	let mut static_methods = Vec::new();
+	let mut accessors: Vec<Accessor> = Vec::new();
+	for item in input.items.iter_mut() {
+		let method = match item {
+			syn::ImplItem::Method(method) => method,
+			_ => continue,
+		};
+		let kind = method_kind(&mut method.attrs)?;
+		let kind = match kind {
+			Some(kind) => kind,
+			None if matches!(method.vis, syn::Visibility::Public(_)) => MethodKind::Method,
+			None => continue,
+		};
+		let receiver = method_receiver(&method.sig)?;
+		let ident = &method.sig.ident;
+		let name = ident.to_string();
+		match (&kind, &receiver) {
+			(MethodKind::Constructor, MethodReceiver::None) => {}
+			(MethodKind::Constructor, _) => {
+				return Err(syn::Error::new(
+					method.sig.span(),
+					"a constructor must not take a receiver",
+				))
+			}
+			(MethodKind::Getter, MethodReceiver::None)
+			| (MethodKind::Setter, MethodReceiver::None) => {
+				return Err(syn::Error::new(
+					method.sig.span(),
+					"an accessor must take &self or &mut self",
+				))
+			}
+			_ => {}
+		}
+		let closure = method_closure(&self_ty, &method.sig, &kind, &receiver)?;
+		strip_default_attrs(method.sig.inputs.iter_mut());
+		match kind {
+			MethodKind::Constructor => {
+				if constructor.is_some() {
+					return Err(syn::Error::new(
+						method.sig.span(),
+						"a class can only have one constructor",
+					));
+				}
+				constructor = Some(closure);
+			}
+			MethodKind::Method => match receiver {
+				MethodReceiver::None => static_methods.push((name, closure)),
+				_ => methods.push((name, closure)),
+			},
+			MethodKind::Getter | MethodKind::Setter => {
+				let name = match kind {
+					MethodKind::Setter => name.strip_prefix("set_").unwrap_or(&name).to_owned(),
+					_ => name,
+				};
+				let index = match accessors.iter().position(|accessor| accessor.name == name) {
+					Some(index) => index,
+					None => {
+						accessors.push(Accessor {
+							name,
+							getter: None,
+							setter: None,
+						});
+						accessors.len() - 1
+					}
+				};
+				let accessor = &mut accessors[index];
+				let slot = match kind {
+					MethodKind::Getter => &mut accessor.getter,
+					_ => &mut accessor.setter,
+				};
+				if slot.is_some() {
+					return Err(syn::Error::new(
+						method.sig.span(),
+						"duplicate accessor for this property",
+					));
+				}
+				*slot = Some(closure);
+			}
+		}
+	}
+	let constructor = constructor.unwrap_or_else(|| {
+		quote! {
+			|env, ctx| Err(node_api::Error::message("this class cannot be constructed"))
+		}
+	});
+	let methods = methods.iter().map(|(name, closure)| {
+		quote! { .method(#name, #closure) }
+	});
+	let static_methods = static_methods.iter().map(|(name, closure)| {
+		quote! { .static_method(#name, #closure) }
+	});
+	let accessors = accessors.iter().map(|accessor| {
+		let name = &accessor.name;
+		let getter = accessor
+			.getter
+			.as_ref()
+			.map(|getter| quote! { .getter(#getter) });
+		let setter = accessor
+			.setter
+			.as_ref()
+			.map(|setter| quote! { .setter(#setter) });
+		quote! {
+			.property(node_api::PropertyDescriptor::accessor(#name)#getter #setter.configurable(true))
+		}
+	});
+	let code = quote! {
+		#input
+		impl #self_ty {
+			#[allow(unused_variables)]
+			pub fn define_class<'a>(env: node_api::Env<'a>) -> node_api::Result<node_api::Function<'a>> {
+				node_api::Class::<Self>::new(env, <Self as node_api::NativeClass>::NAME, #constructor)
+					#(#methods)*
+					#(#static_methods)*
+					#(#accessors)*
+					.define()
+			}
+		}
+	};
+	Ok(code)
+}
+
+fn method_kind(attrs: &mut Vec<syn::Attribute>) -> syn::Result<Option<MethodKind>> {
+	let mut kind = None;
+	let mut error = None;
+	attrs.retain(|attr| {
+		let attr_kind = if attr.path.is_ident("constructor") {
+			MethodKind::Constructor
+		} else if attr.path.is_ident("getter") {
+			MethodKind::Getter
+		} else if attr.path.is_ident("setter") {
+			MethodKind::Setter
+		} else {
+			return true;
+		};
+		if kind.is_some() {
+			error = Some(syn::Error::new(
+				attr.span(),
+				"a method can only have one of #[constructor], #[getter] and #[setter]",
+			));
+		}
+		kind = Some(attr_kind);
+		false
+	});
+	match error {
+		Some(error) => Err(error),
+		None => Ok(kind),
+	}
+}
+
+fn method_receiver(sig: &syn::Signature) -> syn::Result<MethodReceiver> {
+	match sig.receiver() {
+		None => Ok(MethodReceiver::None),
+		Some(syn::FnArg::Receiver(receiver)) if receiver.reference.is_some() => {
+			if receiver.mutability.is_some() {
+				Ok(MethodReceiver::Mut)
+			} else {
+				Ok(MethodReceiver::Ref)
+			}
+		}
+		Some(receiver) => Err(syn::Error::new(
+			receiver.span(),
+			"the receiver must be &self or &mut self",
+		)),
+	}
+}
+
+fn method_closure(
+	self_ty: &syn::Type,
+	sig: &syn::Signature,
+	kind: &MethodKind,
+	receiver: &MethodReceiver,
+) -> syn::Result<proc_macro2::TokenStream> {
+	let ident = &sig.ident;
+	let inputs = sig
+		.inputs
+		.iter()
+		.filter(|input| matches!(input, syn::FnArg::Typed(_)));
+	let inputs = typed_args(inputs)?;
+	let call_args = inputs.iter().map(|input| input.ident).collect::<Vec<_>>();
+	let (env_inputs, args): (Vec<_>, Vec<_>) = inputs
+		.into_iter()
+		.partition(|input| is_type(input.ty, "Env"));
+	let env_statements = env_inputs.iter().map(|input| {
+		let ident = input.ident;
+		quote! { let #ident = env; }
+	});
+	let from_node_api_statements = from_node_api_statements(
+		&args,
+		quote! { ctx.len() },
+		|i| quote! { ctx.arg(#i)? },
+		|i| quote! { ctx.args().iter().copied().skip(#i) },
+	);
+	let (this_statement, receiver_arg) = match receiver {
+		MethodReceiver::None => (quote! {}, quote! {}),
+		MethodReceiver::Ref => (
+			quote! {
+				let this = ctx.this().as_object()?;
+				let this = this.unwrap::<#self_ty>()?;
+			},
+			quote! { &*this, },
+		),
+		MethodReceiver::Mut => (
+			quote! {
+				let this = ctx.this().as_object()?;
+				let mut this = this.unwrap_mut::<#self_ty>()?;
+			},
+			quote! { &mut *this, },
+		),
+	};
+	let into_error = into_error(quote! { error });
+	let output_statement = match kind {
+		MethodKind::Constructor => quote! { Ok(output) },
+		MethodKind::Setter => quote! { Ok(node_api::Undefined::new(env)?.value()) },
+		MethodKind::Getter | MethodKind::Method => {
+			quote! { node_api::IntoNodeApi::into_node_api(output, env) }
+		}
+	};
+	let code = quote! {
+		|env, ctx| {
+			#this_statement
+			#(#env_statements)*
+			#(#from_node_api_statements)*
+			let output = <#self_ty>::#ident(#receiver_arg #(#call_args),*).map_err(|error| #into_error)?;
+			#output_statement
+		}
+	};
+	Ok(code)
+}
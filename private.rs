@@ -0,0 +1,199 @@
+use crate::{
+	executor, sys::napi_valuetype, Deferred, Env, Error, ErrorKind, IntoNodeApi, Promise, Result,
+	ThreadsafeFunction, ThreadsafeFunctionCallMode, Value,
+};
+use std::{
+	any::Any,
+	backtrace::{Backtrace, BacktraceStatus},
+	cell::RefCell,
+	fmt::Display,
+	future::Future,
+	pin::Pin,
+	sync::Once,
+	task::{Context, Poll},
+};
+
+thread_local! {
+	static PANIC_BACKTRACE: RefCell<Option<Backtrace>> = const { RefCell::new(None) };
+}
+
+/// Install a panic hook that records a backtrace for `throw_panic` and then calls the previous hook. The backtrace is only captured if enabled with `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE`.
+fn install_panic_hook() {
+	static INSTALL_PANIC_HOOK: Once = Once::new();
+	INSTALL_PANIC_HOOK.call_once(|| {
+		let previous_hook = std::panic::take_hook();
+		std::panic::set_hook(Box::new(move |info| {
+			PANIC_BACKTRACE.with(|backtrace| {
+				backtrace.borrow_mut().replace(Backtrace::capture());
+			});
+			previous_hook(info);
+		}));
+	});
+}
+
+/// Run `f`, catching any panic so that it can be passed to `throw_panic` or `panic_message`.
+pub fn catch_unwind<R>(f: impl FnOnce() -> R) -> std::thread::Result<R> {
+	install_panic_hook();
+	PANIC_BACKTRACE.with(|backtrace| backtrace.borrow_mut().take());
+	std::panic::catch_unwind(std::panic::AssertUnwindSafe(f))
+}
+
+/// Get the message of a panic from its payload.
+pub fn panic_message(payload: &(dyn Any + Send)) -> std::string::String {
+	if let Some(message) = payload.downcast_ref::<&str>() {
+		(*message).to_owned()
+	} else if let Some(message) = payload.downcast_ref::<std::string::String>() {
+		message.clone()
+	} else {
+		"A panic occurred.".to_owned()
+	}
+}
+
+/// Throw a JS error for a panic caught with `catch_unwind`. Its message is the panic message, and its `rustBacktrace` property is the backtrace if one was captured.
+pub fn throw_panic(env: Env, payload: Box<dyn Any + Send>) {
+	let message = panic_message(payload.as_ref());
+	let backtrace = PANIC_BACKTRACE.with(|backtrace| backtrace.borrow_mut().take());
+	let error = (|| {
+		let mut error = Error::message(message.as_str())
+			.into_node_api(env)?
+			.as_object()?;
+		if let Some(backtrace) = backtrace {
+			if backtrace.status() == BacktraceStatus::Captured {
+				error.set_named(
+					"rustBacktrace",
+					crate::String::new(env, &backtrace.to_string())?,
+				)?;
+			}
+		}
+		Ok::<_, Error>(error.value())
+	})();
+	match error {
+		Ok(error) => env.throw_value(error),
+		Err(_) => env.throw(Error::message(message)),
+	}
+}
+
+/// Create the error thrown when the argument at `index` cannot be converted to the type of the parameter `name`. A value of the right JS type that the conversion rejects with a `RangeError`, such as a number that does not fit, becomes a `RangeError` with the code `ERR_OUT_OF_RANGE`. Any other failure becomes a `TypeError` with the code `ERR_INVALID_ARG_TYPE`. An exception thrown by JS during the conversion is passed through.
+pub fn invalid_arg_error(value: Value, index: usize, name: &str, ty: &str, error: Error) -> Error {
+	if let Error::JsException(_) = error {
+		return error;
+	}
+	let received = match value.value_type() {
+		Ok(napi_valuetype::napi_undefined) => "undefined",
+		Ok(napi_valuetype::napi_null) => "null",
+		Ok(napi_valuetype::napi_boolean) => "boolean",
+		Ok(napi_valuetype::napi_number) => "number",
+		Ok(napi_valuetype::napi_string) => "string",
+		Ok(napi_valuetype::napi_symbol) => "symbol",
+		Ok(napi_valuetype::napi_object) => "object",
+		Ok(napi_valuetype::napi_function) => "function",
+		Ok(napi_valuetype::napi_external) => "external",
+		Ok(napi_valuetype::napi_bigint) => "bigint",
+		Err(_) => "unknown",
+	};
+	if error.kind() == ErrorKind::RangeError {
+		let received = match value.as_number().and_then(|number| number.get()) {
+			Ok(number) => number.to_string(),
+			Err(_) => format!("type {}", received),
+		};
+		return Error::range_error(format!(
+			"The value of \"{}\" (at position {}) is out of range for type {}. Received {} ({})",
+			name,
+			index + 1,
+			ty,
+			received,
+			error,
+		))
+		.with_code("ERR_OUT_OF_RANGE");
+	}
+	Error::type_error(format!(
+		"The \"{}\" argument (at position {}) must be of type {}. Received type {} ({})",
+		name,
+		index + 1,
+		ty,
+		received,
+		error,
+	))
+	.with_code("ERR_INVALID_ARG_TYPE")
+}
+
+/// Return the `TypeError` thrown when the required argument at `index` was not passed.
+pub fn require_arg(argc: usize, index: usize, name: &str) -> Result<()> {
+	if index < argc {
+		return Ok(());
+	}
+	Err(
+		Error::type_error(format!("The \"{}\" argument must be specified", name))
+			.with_code("ERR_MISSING_ARGS"),
+	)
+}
+
+/// Converts the error returned by a function wrapped by a macro into an `Error`. An `Error` is passed through unchanged so that its kind and code are kept. Any other error that implements `Display` becomes `Error::Message`. Call `.into_error()` with `IntoNodeApiError` and `IntoMessageError` in scope.
+pub struct ErrorConverter<E>(pub E);
+
+pub trait IntoNodeApiError {
+	fn into_error(self) -> Error;
+}
+
+impl IntoNodeApiError for ErrorConverter<Error> {
+	fn into_error(self) -> Error {
+		self.0
+	}
+}
+
+pub trait IntoMessageError {
+	fn into_error(self) -> Error;
+}
+
+impl<E: Display> IntoMessageError for &ErrorConverter<E> {
+	fn into_error(self) -> Error {
+		Error::message(self.0.to_string())
+	}
+}
+
+/// Run `future` with `executor::spawn` and return a promise that is settled with its output on the JS thread.
+pub fn spawn_future<'a, F, T>(env: Env<'a>, future: F) -> Result<Value<'a>>
+where
+	F: Future<Output = Result<T>> + Send + 'static,
+	T: for<'b> IntoNodeApi<'b> + Send + 'static,
+{
+	let (deferred, promise) = Promise::new(env)?;
+	let deferred: RefCell<Option<Deferred>> = RefCell::new(Some(deferred));
+	let tsfn =
+		ThreadsafeFunction::with_call_js(env, None, 0, move |_env, _func, output: Result<T>| {
+			let deferred = deferred
+				.borrow_mut()
+				.take()
+				.ok_or_else(|| Error::message("the promise was already settled"))?;
+			match output {
+				Ok(output) => deferred.resolve(output),
+				Err(error) => deferred.reject(error),
+			}
+		})?;
+	executor::spawn(async move {
+		let output = match (CatchUnwind { future }).await {
+			Ok(output) => output,
+			Err(payload) => Err(Error::message(panic_message(payload.as_ref()))),
+		};
+		tsfn.call(output, ThreadsafeFunctionCallMode::Blocking).ok();
+	});
+	Ok(promise.value())
+}
+
+/// A future that catches a panic while polling `future`.
+struct CatchUnwind<F> {
+	future: F,
+}
+
+impl<F: Future> Future for CatchUnwind<F> {
+	type Output = std::thread::Result<F::Output>;
+
+	fn poll(self: Pin<&mut Self>, context: &mut Context) -> Poll<Self::Output> {
+		let future = unsafe { self.map_unchecked_mut(|this| &mut this.future) };
+		match catch_unwind(|| future.poll(context)) {
+			Ok(Poll::Pending) => Poll::Pending,
+			Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+			Err(payload) => Poll::Ready(Err(payload)),
+		}
+	}
+}
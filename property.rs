@@ -0,0 +1,332 @@
+use crate::{
+	callback::{run_callback, Callback},
+	sys::{
+		napi_add_finalizer, napi_callback_info, napi_env, napi_property_attributes,
+		napi_property_descriptor, napi_status, napi_value,
+	},
+	CallContext, Env, Error, Result, String, Symbol, Value,
+};
+use std::{ffi::c_void, ffi::CString, rc::Rc};
+
+pub enum PropertyKey<'a> {
+	Name(std::string::String),
+	Value(Value<'a>),
+}
+
+impl<'a> From<&str> for PropertyKey<'a> {
+	fn from(value: &str) -> PropertyKey<'a> {
+		PropertyKey::Name(value.to_owned())
+	}
+}
+
+impl<'a> From<std::string::String> for PropertyKey<'a> {
+	fn from(value: std::string::String) -> PropertyKey<'a> {
+		PropertyKey::Name(value)
+	}
+}
+
+impl<'a> From<Value<'a>> for PropertyKey<'a> {
+	fn from(value: Value<'a>) -> PropertyKey<'a> {
+		PropertyKey::Value(value)
+	}
+}
+
+impl<'a> From<String<'a>> for PropertyKey<'a> {
+	fn from(value: String<'a>) -> PropertyKey<'a> {
+		PropertyKey::Value(value.value())
+	}
+}
+
+impl<'a> From<Symbol<'a>> for PropertyKey<'a> {
+	fn from(value: Symbol<'a>) -> PropertyKey<'a> {
+		PropertyKey::Value(value.value())
+	}
+}
+
+/// A property to define with `Object::define_properties`. Properties are read-only, non-enumerable and non-configurable unless the corresponding flag is set.
+pub struct PropertyDescriptor<'a> {
+	key: PropertyKey<'a>,
+	kind: PropertyKind<'a>,
+	attributes: napi_property_attributes,
+}
+
+enum PropertyKind<'a> {
+	Value(Value<'a>),
+	Method(Rc<Callback>),
+	Accessor {
+		getter: Option<Rc<Callback>>,
+		setter: Option<Rc<Callback>>,
+	},
+}
+
+/// An accessor property created with `PropertyDescriptor::accessor`. Convert it into a `PropertyDescriptor` with `into`.
+pub struct AccessorDescriptor<'a> {
+	key: PropertyKey<'a>,
+	getter: Option<Rc<Callback>>,
+	setter: Option<Rc<Callback>>,
+	attributes: napi_property_attributes,
+}
+
+impl<'a> PropertyDescriptor<'a> {
+	fn new(key: impl Into<PropertyKey<'a>>, kind: PropertyKind<'a>) -> PropertyDescriptor<'a> {
+		PropertyDescriptor {
+			key: key.into(),
+			kind,
+			attributes: napi_property_attributes::napi_default,
+		}
+	}
+
+	pub fn value(
+		key: impl Into<PropertyKey<'a>>,
+		value: impl Into<Value<'a>>,
+	) -> PropertyDescriptor<'a> {
+		PropertyDescriptor::new(key, PropertyKind::Value(value.into()))
+	}
+
+	pub fn method<F>(key: impl Into<PropertyKey<'a>>, f: F) -> PropertyDescriptor<'a>
+	where
+		F: for<'b> Fn(Env<'b>, CallContext<'b>) -> Result<Value<'b>> + 'static,
+	{
+		PropertyDescriptor::new(key, PropertyKind::Method(Rc::new(f)))
+	}
+
+	/// Create an accessor property. Add a getter and a setter with `getter` and `setter`.
+	pub fn accessor(key: impl Into<PropertyKey<'a>>) -> AccessorDescriptor<'a> {
+		AccessorDescriptor {
+			key: key.into(),
+			getter: None,
+			setter: None,
+			attributes: napi_property_attributes::napi_default,
+		}
+	}
+
+	pub fn writable(mut self, writable: bool) -> PropertyDescriptor<'a> {
+		set_attribute(
+			&mut self.attributes,
+			napi_property_attributes::napi_writable,
+			writable,
+		);
+		self
+	}
+
+	pub fn enumerable(mut self, enumerable: bool) -> PropertyDescriptor<'a> {
+		set_attribute(
+			&mut self.attributes,
+			napi_property_attributes::napi_enumerable,
+			enumerable,
+		);
+		self
+	}
+
+	pub fn configurable(mut self, configurable: bool) -> PropertyDescriptor<'a> {
+		set_attribute(
+			&mut self.attributes,
+			napi_property_attributes::napi_configurable,
+			configurable,
+		);
+		self
+	}
+
+	pub(crate) fn static_(mut self) -> PropertyDescriptor<'a> {
+		set_attribute(
+			&mut self.attributes,
+			napi_property_attributes::napi_static,
+			true,
+		);
+		self
+	}
+}
+
+impl<'a> AccessorDescriptor<'a> {
+	pub fn getter<F>(mut self, f: F) -> AccessorDescriptor<'a>
+	where
+		F: for<'b> Fn(Env<'b>, CallContext<'b>) -> Result<Value<'b>> + 'static,
+	{
+		self.getter = Some(Rc::new(f));
+		self
+	}
+
+	/// Add a setter. The new value is the first argument of the `CallContext` and the returned value is ignored.
+	pub fn setter<F>(mut self, f: F) -> AccessorDescriptor<'a>
+	where
+		F: for<'b> Fn(Env<'b>, CallContext<'b>) -> Result<Value<'b>> + 'static,
+	{
+		self.setter = Some(Rc::new(f));
+		self
+	}
+
+	pub fn enumerable(mut self, enumerable: bool) -> AccessorDescriptor<'a> {
+		set_attribute(
+			&mut self.attributes,
+			napi_property_attributes::napi_enumerable,
+			enumerable,
+		);
+		self
+	}
+
+	pub fn configurable(mut self, configurable: bool) -> AccessorDescriptor<'a> {
+		set_attribute(
+			&mut self.attributes,
+			napi_property_attributes::napi_configurable,
+			configurable,
+		);
+		self
+	}
+}
+
+impl<'a> From<AccessorDescriptor<'a>> for PropertyDescriptor<'a> {
+	fn from(accessor: AccessorDescriptor<'a>) -> PropertyDescriptor<'a> {
+		PropertyDescriptor {
+			key: accessor.key,
+			kind: PropertyKind::Accessor {
+				getter: accessor.getter,
+				setter: accessor.setter,
+			},
+			attributes: accessor.attributes,
+		}
+	}
+}
+
+fn set_attribute(
+	attributes: &mut napi_property_attributes,
+	attribute: napi_property_attributes,
+	enabled: bool,
+) {
+	if enabled {
+		*attributes |= attribute;
+	} else {
+		*attributes = napi_property_attributes(attributes.0 & !attribute.0);
+	}
+}
+
+struct PropertyCallbacks {
+	method: Option<Rc<Callback>>,
+	getter: Option<Rc<Callback>>,
+	setter: Option<Rc<Callback>>,
+}
+
+/// The raw descriptors for a list of `PropertyDescriptor`s, along with the data they point to.
+pub(crate) struct RawPropertyDescriptors {
+	_names: Vec<CString>,
+	descriptors: Vec<napi_property_descriptor>,
+	callbacks: Vec<Rc<PropertyCallbacks>>,
+}
+
+impl RawPropertyDescriptors {
+	pub fn new(properties: &[PropertyDescriptor]) -> Result<RawPropertyDescriptors> {
+		let mut names = Vec::new();
+		let mut descriptors = Vec::with_capacity(properties.len());
+		let mut callbacks = Vec::new();
+		for property in properties {
+			let (utf8name, name) = match &property.key {
+				PropertyKey::Name(name) => {
+					let name = CString::new(name.as_str())
+						.map_err(|_| Error::message("property name must not contain a nul byte"))?;
+					let utf8name = name.as_ptr();
+					names.push(name);
+					(utf8name, std::ptr::null_mut())
+				}
+				PropertyKey::Value(value) => (std::ptr::null(), value.raw()),
+			};
+			let (value, method, getter, setter) = match &property.kind {
+				PropertyKind::Value(value) => (Some(*value), None, None, None),
+				PropertyKind::Method(method) => (None, Some(method.clone()), None, None),
+				PropertyKind::Accessor { getter, setter } => {
+					(None, None, getter.clone(), setter.clone())
+				}
+			};
+			let raw_method = method.as_ref().map(|_| call_method as _);
+			let raw_getter = getter.as_ref().map(|_| call_getter as _);
+			let raw_setter = setter.as_ref().map(|_| call_setter as _);
+			let data = if method.is_some() || getter.is_some() || setter.is_some() {
+				let property_callbacks = Rc::new(PropertyCallbacks {
+					method,
+					getter,
+					setter,
+				});
+				let data = Rc::as_ptr(&property_callbacks) as *mut c_void;
+				callbacks.push(property_callbacks);
+				data
+			} else {
+				std::ptr::null_mut()
+			};
+			descriptors.push(napi_property_descriptor {
+				utf8name,
+				name,
+				method: raw_method,
+				getter: raw_getter,
+				setter: raw_setter,
+				value: value
+					.map(|value| value.raw())
+					.unwrap_or_else(std::ptr::null_mut),
+				attributes: property.attributes,
+				data,
+			});
+		}
+		Ok(RawPropertyDescriptors {
+			_names: names,
+			descriptors,
+			callbacks,
+		})
+	}
+
+	pub fn descriptors(&self) -> &[napi_property_descriptor] {
+		&self.descriptors
+	}
+
+	/// Keep the callbacks alive until `value` is garbage collected. If the finalizer cannot be added, the callbacks are leaked rather than freed, because `value` may already refer to them.
+	pub fn attach(&self, env: Env, value: napi_value) -> Result<()> {
+		if self.callbacks.is_empty() {
+			return Ok(());
+		}
+		let callbacks = Box::into_raw(Box::new(self.callbacks.clone()));
+		unsafe {
+			let status = napi_add_finalizer(
+				env.raw(),
+				value,
+				callbacks as *mut c_void,
+				Some(finalize_property_callbacks),
+				std::ptr::null_mut(),
+				std::ptr::null_mut(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+		}
+		Ok(())
+	}
+}
+
+unsafe fn call_property_callback(
+	env: napi_env,
+	info: napi_callback_info,
+	select: fn(&PropertyCallbacks) -> Option<&Rc<Callback>>,
+) -> napi_value {
+	let env = Env::from_raw(env);
+	run_callback(env, || {
+		let context = CallContext::from_raw(env, info)?;
+		let callbacks = &*(context.data() as *const PropertyCallbacks);
+		let callback = select(callbacks).ok_or_else(|| Error::message("missing callback"))?;
+		callback(env, context)
+	})
+}
+
+unsafe extern "C" fn call_method(env: napi_env, info: napi_callback_info) -> napi_value {
+	call_property_callback(env, info, |callbacks| callbacks.method.as_ref())
+}
+
+unsafe extern "C" fn call_getter(env: napi_env, info: napi_callback_info) -> napi_value {
+	call_property_callback(env, info, |callbacks| callbacks.getter.as_ref())
+}
+
+unsafe extern "C" fn call_setter(env: napi_env, info: napi_callback_info) -> napi_value {
+	call_property_callback(env, info, |callbacks| callbacks.setter.as_ref())
+}
+
+unsafe extern "C" fn finalize_property_callbacks(
+	_env: napi_env,
+	data: *mut c_void,
+	_hint: *mut c_void,
+) {
+	drop(Box::from_raw(data as *mut Vec<Rc<PropertyCallbacks>>));
+}
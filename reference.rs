@@ -0,0 +1,129 @@
+use crate::{
+	sys::{
+		napi_create_reference, napi_delete_reference, napi_env, napi_get_reference_value, napi_ref,
+		napi_reference_ref, napi_reference_unref, napi_status,
+	},
+	Array, Env, Error, FromNodeAPI, Result, Value,
+};
+use std::mem::MaybeUninit;
+
+/// An owned reference to a JS value that can be stored beyond the lifetime of the current callback. A strong reference keeps the value alive. A weak reference does not, so `get` returns `None` once the value has been garbage collected. Primitive values are never garbage collected, so a reference to a primitive always behaves as a strong reference. The reference is deleted when dropped, so it must be dropped on the thread that created it.
+#[derive(Debug)]
+pub struct Reference {
+	env: napi_env,
+	raw: napi_ref,
+	/// Node-API can only reference objects, so primitives are stored in an array that is referenced strongly. This is the count reported by `refer` and `unref` for such a reference.
+	boxed_count: Option<u32>,
+}
+
+impl Reference {
+	/// Create a strong reference to `value`.
+	pub fn new(value: Value) -> Result<Reference> {
+		Reference::with_count(value, 1)
+	}
+
+	/// Create a weak reference to `value`.
+	pub fn weak(value: Value) -> Result<Reference> {
+		Reference::with_count(value, 0)
+	}
+
+	fn with_count(value: Value, count: u32) -> Result<Reference> {
+		let env = value.env();
+		let is_object = value.is_object()? || value.is_function()? || value.is_external()?;
+		let (target, boxed_count) = if is_object {
+			(value, None)
+		} else {
+			let mut array = Array::new(env)?;
+			array.set(0, value)?;
+			(array.value(), Some(count))
+		};
+		let count = if is_object { count } else { 1 };
+		let raw = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_create_reference(env.raw(), target.raw(), count, result.as_mut_ptr());
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			result.assume_init()
+		};
+		Ok(Reference {
+			env: env.raw(),
+			raw,
+			boxed_count,
+		})
+	}
+
+	pub fn raw(&self) -> napi_ref {
+		self.raw
+	}
+
+	/// Get the referenced value, or `None` if this is a weak reference and the value has been garbage collected.
+	pub fn get<'a>(&self, env: Env<'a>) -> Result<Option<Value<'a>>> {
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_get_reference_value(env.raw(), self.raw, result.as_mut_ptr());
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			result.assume_init()
+		};
+		if value.is_null() {
+			return Ok(None);
+		}
+		let value = Value::from_raw(env, value);
+		if self.boxed_count.is_some() {
+			return Ok(Some(value.as_array()?.get(0)?));
+		}
+		Ok(Some(value))
+	}
+
+	/// Get the referenced value and convert it to `T`.
+	pub fn get_as<'a, T>(&self, env: Env<'a>) -> Result<Option<T>>
+	where
+		T: FromNodeAPI<'a>,
+	{
+		self.get(env)?.map(T::from_node_api).transpose()
+	}
+
+	/// Increment the reference count, making a weak reference strong. Returns the new count.
+	pub fn refer(&mut self, env: Env) -> Result<u32> {
+		if let Some(count) = self.boxed_count.as_mut() {
+			*count += 1;
+			return Ok(*count);
+		}
+		unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_reference_ref(env.raw(), self.raw, result.as_mut_ptr());
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			Ok(result.assume_init())
+		}
+	}
+
+	/// Decrement the reference count. The reference becomes weak when the count reaches zero. Returns the new count.
+	pub fn unref(&mut self, env: Env) -> Result<u32> {
+		if let Some(count) = self.boxed_count.as_mut() {
+			*count = count
+				.checked_sub(1)
+				.ok_or_else(|| Error::message("reference count is already zero"))?;
+			return Ok(*count);
+		}
+		unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_reference_unref(env.raw(), self.raw, result.as_mut_ptr());
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			Ok(result.assume_init())
+		}
+	}
+}
+
+impl Drop for Reference {
+	fn drop(&mut self) {
+		unsafe {
+			napi_delete_reference(self.env, self.raw);
+		}
+	}
+}
@@ -1,4 +1,7 @@
-use crate::{Array, ArrayBuffer, Env, Error, IntoNodeApi, Null, Object, Result, String, Value};
+use crate::{
+	env::HandleScope, Array, ArrayBuffer, Env, Error, IntoNodeApi, Null, Object, Result, String,
+	Value,
+};
 use serde::Serialize;
 
 impl<'a> serde::Serializer for Env<'a> {
@@ -192,6 +195,7 @@ impl<'a> serde::ser::SerializeSeq for SeqSerializer<'a> {
 	where
 		T: serde::Serialize,
 	{
+		let _scope = HandleScope::open(self.array.env())?;
 		let value = value.serialize(self.array.env())?;
 		self.array.push(value)?;
 		Ok(())
@@ -221,6 +225,7 @@ impl<'a> serde::ser::SerializeTuple for TupleSerializer<'a> {
 	where
 		T: serde::Serialize,
 	{
+		let _scope = HandleScope::open(self.array.env())?;
 		let value = value.serialize(self.array.env())?;
 		self.array.push(value)?;
 		Ok(())
@@ -250,6 +255,7 @@ impl<'a> serde::ser::SerializeTupleStruct for TupleStructSerializer<'a> {
 	where
 		T: serde::Serialize,
 	{
+		let _scope = HandleScope::open(self.array.env())?;
 		let value = value.serialize(self.array.env())?;
 		self.array.push(value)?;
 		Ok(())
@@ -286,6 +292,7 @@ impl<'a> serde::ser::SerializeTupleVariant for TupleVariantSerializer<'a> {
 	where
 		T: serde::Serialize,
 	{
+		let _scope = HandleScope::open(self.array.env())?;
 		let value = value.serialize(self.array.env())?;
 		self.array.push(value)?;
 		Ok(())
@@ -330,6 +337,7 @@ impl<'a> serde::ser::SerializeMap for MapSerializer<'a> {
 			.key
 			.take()
 			.expect("serialize_value called before serialize_key");
+		let _scope = HandleScope::open(self.object.env())?;
 		let value = value.serialize(self.object.env())?;
 		self.object.set(key, value)?;
 		Ok(())
@@ -363,6 +371,7 @@ impl<'a> serde::ser::SerializeStruct for StructSerializer<'a> {
 	where
 		T: serde::Serialize,
 	{
+		let _scope = HandleScope::open(self.object.env())?;
 		let key = serde::Serializer::serialize_str(self.object.env(), key)?;
 		let value = value.serialize(self.object.env())?;
 		self.object.set(key, value)?;
@@ -397,6 +406,7 @@ impl<'a> serde::ser::SerializeStructVariant for StructVariantSerializer<'a> {
 	where
 		T: serde::Serialize,
 	{
+		let _scope = HandleScope::open(self.object.env())?;
 		let key = serde::Serializer::serialize_str(self.object.env(), key)?;
 		let value = value.serialize(self.object.env())?;
 		self.object.set(key, value)?;
@@ -1,4 +1,4 @@
-#![allow(non_camel_case_types, improper_ctypes)]
+#![allow(non_camel_case_types, non_upper_case_globals, improper_ctypes)]
 
 use std::os::raw::{c_char, c_int, c_uint, c_void};
 type size_t = usize;
@@ -91,14 +91,29 @@ pub struct napi_threadsafe_function__ {
 
 pub type napi_threadsafe_function = *mut napi_threadsafe_function__;
 
-#[repr(u32)]
+#[repr(transparent)]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub enum napi_property_attributes {
-	napi_default = 0,
-	napi_writable = 1,
-	napi_enumerable = 2,
-	napi_configurable = 4,
-	napi_static = 1024,
+pub struct napi_property_attributes(pub c_uint);
+
+impl napi_property_attributes {
+	pub const napi_default: napi_property_attributes = napi_property_attributes(0);
+	pub const napi_writable: napi_property_attributes = napi_property_attributes(1);
+	pub const napi_enumerable: napi_property_attributes = napi_property_attributes(2);
+	pub const napi_configurable: napi_property_attributes = napi_property_attributes(4);
+	pub const napi_static: napi_property_attributes = napi_property_attributes(1024);
+}
+
+impl std::ops::BitOr for napi_property_attributes {
+	type Output = napi_property_attributes;
+	fn bitor(self, other: napi_property_attributes) -> napi_property_attributes {
+		napi_property_attributes(self.0 | other.0)
+	}
+}
+
+impl std::ops::BitOrAssign for napi_property_attributes {
+	fn bitor_assign(&mut self, other: napi_property_attributes) {
+		self.0 |= other.0;
+	}
 }
 
 #[repr(u32)]
@@ -232,15 +247,30 @@ pub enum napi_key_collection_mode {
 	napi_key_own_only = 1,
 }
 
-#[repr(u32)]
+#[repr(transparent)]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub enum napi_key_filter {
-	napi_key_all_properties = 0,
-	napi_key_writable = 1,
-	napi_key_enumerable = 2,
-	napi_key_configurable = 4,
-	napi_key_skip_strings = 8,
-	napi_key_skip_symbols = 16,
+pub struct napi_key_filter(pub c_uint);
+
+impl napi_key_filter {
+	pub const napi_key_all_properties: napi_key_filter = napi_key_filter(0);
+	pub const napi_key_writable: napi_key_filter = napi_key_filter(1);
+	pub const napi_key_enumerable: napi_key_filter = napi_key_filter(2);
+	pub const napi_key_configurable: napi_key_filter = napi_key_filter(4);
+	pub const napi_key_skip_strings: napi_key_filter = napi_key_filter(8);
+	pub const napi_key_skip_symbols: napi_key_filter = napi_key_filter(16);
+}
+
+impl std::ops::BitOr for napi_key_filter {
+	type Output = napi_key_filter;
+	fn bitor(self, other: napi_key_filter) -> napi_key_filter {
+		napi_key_filter(self.0 | other.0)
+	}
+}
+
+impl std::ops::BitOrAssign for napi_key_filter {
+	fn bitor_assign(&mut self, other: napi_key_filter) {
+		self.0 |= other.0;
+	}
 }
 
 #[repr(u32)]
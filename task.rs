@@ -0,0 +1,150 @@
+use crate::{
+	private,
+	sys::{
+		napi_async_work, napi_cancel_async_work, napi_create_async_work, napi_delete_async_work,
+		napi_env, napi_queue_async_work, napi_status,
+	},
+	Deferred, Env, Error, IntoNodeApi, Promise, Result, String,
+};
+use std::{any::Any, cell::Cell, ffi::c_void, fmt::Display, mem::MaybeUninit, rc::Rc};
+
+/// A task that runs a closure on the libuv threadpool and settles a promise with its result on the main thread.
+pub struct AsyncTask {
+	env: napi_env,
+	raw: napi_async_work,
+	completed: Rc<Cell<bool>>,
+}
+
+struct Work<F, T, E> {
+	compute: Option<F>,
+	output: Option<std::thread::Result<Result<T, E>>>,
+}
+
+struct TaskData<F, T, E> {
+	work: Work<F, T, E>,
+	deferred: Deferred,
+	raw: napi_async_work,
+	completed: Rc<Cell<bool>>,
+}
+
+impl AsyncTask {
+	/// Queue `compute` to run on the threadpool. The returned promise resolves with its output converted with `IntoNodeApi`, or rejects with its error. An `Error` keeps its kind and code, and any other error becomes an `Error` with its message.
+	pub fn new<'a, F, T, E>(env: Env<'a>, compute: F) -> Result<(AsyncTask, Promise<'a>)>
+	where
+		F: FnOnce() -> Result<T, E> + Send + 'static,
+		T: for<'b> IntoNodeApi<'b> + Send,
+		E: Display + Send + 'static,
+	{
+		let (deferred, promise) = Promise::new(env)?;
+		let completed = Rc::new(Cell::new(false));
+		let data: Box<TaskData<F, T, E>> = Box::new(TaskData {
+			work: Work {
+				compute: Some(compute),
+				output: None,
+			},
+			deferred,
+			raw: std::ptr::null_mut(),
+			completed: completed.clone(),
+		});
+		let data = Box::into_raw(data);
+		let name = String::new(env, "AsyncTask")?;
+		let raw = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_create_async_work(
+				env.raw(),
+				std::ptr::null_mut(),
+				name.value().raw(),
+				Some(execute::<F, T, E>),
+				Some(complete::<F, T, E>),
+				data as *mut c_void,
+				result.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				drop(Box::from_raw(data));
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			let raw = result.assume_init();
+			(*data).raw = raw;
+			let status = napi_queue_async_work(env.raw(), raw);
+			if status != napi_status::napi_ok {
+				napi_delete_async_work(env.raw(), raw);
+				drop(Box::from_raw(data));
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			raw
+		};
+		let task = AsyncTask {
+			env: env.raw(),
+			raw,
+			completed,
+		};
+		Ok((task, promise))
+	}
+
+	/// Cancel the task if it has not started running. The promise is then rejected. This fails if the task is already running or has completed.
+	pub fn cancel(&self) -> Result<()> {
+		if self.completed.get() {
+			return Err(Error::message("async task has already completed"));
+		}
+		unsafe {
+			let status = napi_cancel_async_work(self.env, self.raw);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env, status));
+			}
+		}
+		Ok(())
+	}
+}
+
+unsafe extern "C" fn execute<F, T, E>(_env: napi_env, data: *mut c_void)
+where
+	F: FnOnce() -> Result<T, E> + Send + 'static,
+{
+	let work = &mut (*(data as *mut TaskData<F, T, E>)).work;
+	if let Some(compute) = work.compute.take() {
+		work.output = Some(private::catch_unwind(compute));
+	}
+}
+
+unsafe extern "C" fn complete<F, T, E>(env: napi_env, status: napi_status, data: *mut c_void)
+where
+	T: for<'b> IntoNodeApi<'b>,
+	E: Display + 'static,
+{
+	let data = Box::from_raw(data as *mut TaskData<F, T, E>);
+	let TaskData {
+		work,
+		deferred,
+		raw,
+		completed,
+	} = *data;
+	completed.set(true);
+	napi_delete_async_work(env, raw);
+	let result = match (status, work.output) {
+		(napi_status::napi_ok, Some(Ok(Ok(output)))) => deferred.resolve(output),
+		(napi_status::napi_ok, Some(Ok(Err(error)))) => deferred.reject(into_error(error)),
+		(napi_status::napi_ok, Some(Err(payload))) => {
+			deferred.reject(Error::message(private::panic_message(payload.as_ref())))
+		}
+		(napi_status::napi_cancelled, _) => {
+			deferred.reject(Error::message("async task was cancelled"))
+		}
+		(status, _) => deferred.reject(Error::node_api(status, "async task failed")),
+	};
+	let env = Env::from_raw(env);
+	if result.is_err() && !env.is_exception_pending() {
+		env.throw_error("failed to settle the promise for an async task");
+	}
+}
+
+/// Pass an `Error` through unchanged so that its kind and code are kept, and convert any other error to an `Error` with its message.
+fn into_error<E: Display + 'static>(error: E) -> Error {
+	if (&error as &dyn Any).is::<Error>() {
+		let error: Box<dyn Any> = Box::new(error);
+		if let Ok(error) = error.downcast::<Error>() {
+			return *error;
+		}
+		unreachable!()
+	}
+	Error::message(error.to_string())
+}
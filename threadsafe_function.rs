@@ -0,0 +1,252 @@
+use crate::{
+	callback::run_callback,
+	sys::{
+		napi_acquire_threadsafe_function, napi_call_threadsafe_function,
+		napi_create_threadsafe_function, napi_env, napi_ref_threadsafe_function,
+		napi_release_threadsafe_function, napi_status, napi_threadsafe_function,
+		napi_threadsafe_function_call_mode, napi_threadsafe_function_release_mode,
+		napi_unref_threadsafe_function, napi_value,
+	},
+	Env, Error, Function, Result, String, Undefined, Value,
+};
+use std::{ffi::c_void, marker::PhantomData, mem::MaybeUninit};
+
+type CallJs<T> = dyn for<'a> Fn(Env<'a>, Option<Function<'a>>, T) -> Result<()>;
+
+/// A handle to a JS function that can be called from any thread. Each message of type `T` is sent to the main thread, converted to arguments, and passed to the function. Cloning a handle acquires the underlying thread-safe function and dropping it releases it. While any handle is alive the event loop is kept running unless `unref` is called.
+pub struct ThreadsafeFunction<T> {
+	raw: napi_threadsafe_function,
+	acquired: bool,
+	_marker: PhantomData<fn(T)>,
+}
+
+unsafe impl<T: Send> Send for ThreadsafeFunction<T> {}
+
+unsafe impl<T: Send> Sync for ThreadsafeFunction<T> {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThreadsafeFunctionCallMode {
+	/// Wait for space in the queue if it is full.
+	Blocking,
+	/// Return `ThreadsafeFunctionError::QueueFull` if the queue is full.
+	NonBlocking,
+}
+
+/// An error from `ThreadsafeFunction::call`. `QueueFull` and `Closing` return the message that could not be sent.
+pub enum ThreadsafeFunctionError<T> {
+	QueueFull(T),
+	Closing(T),
+	Other(Error),
+}
+
+impl<T> ThreadsafeFunction<T>
+where
+	T: Send + 'static,
+{
+	/// Create a thread-safe function that calls `func`. `convert` runs on the main thread to turn each message into the arguments for `func`. A `max_queue_size` of zero means the queue is unbounded.
+	pub fn new<F>(
+		env: Env,
+		func: Function,
+		max_queue_size: usize,
+		convert: F,
+	) -> Result<ThreadsafeFunction<T>>
+	where
+		F: for<'a> Fn(Env<'a>, T) -> Result<Vec<Value<'a>>> + 'static,
+	{
+		ThreadsafeFunction::with_call_js(
+			env,
+			Some(func),
+			max_queue_size,
+			move |env, func, value| {
+				let args = convert(env, value)?;
+				if let Some(func) = func {
+					func.call(Undefined::new(env)?, &args)?;
+				}
+				Ok(())
+			},
+		)
+	}
+
+	pub(crate) fn with_call_js<F>(
+		env: Env,
+		func: Option<Function>,
+		max_queue_size: usize,
+		call_js: F,
+	) -> Result<ThreadsafeFunction<T>>
+	where
+		F: for<'a> Fn(Env<'a>, Option<Function<'a>>, T) -> Result<()> + 'static,
+	{
+		let name = String::new(env, "ThreadsafeFunction")?;
+		let call_js: Box<Box<CallJs<T>>> = Box::new(Box::new(call_js));
+		let call_js = Box::into_raw(call_js);
+		let raw = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_create_threadsafe_function(
+				env.raw(),
+				func.map(|func| func.value().raw())
+					.unwrap_or_else(std::ptr::null_mut),
+				std::ptr::null_mut(),
+				name.value().raw(),
+				max_queue_size,
+				1,
+				call_js as *mut c_void,
+				Some(finalize_call_js::<T>),
+				call_js as *mut c_void,
+				Some(call_js_trampoline::<T>),
+				result.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				drop(Box::from_raw(call_js));
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			result.assume_init()
+		};
+		Ok(ThreadsafeFunction {
+			raw,
+			acquired: true,
+			_marker: PhantomData,
+		})
+	}
+
+	/// Queue a call to the function with `value`. This can be called from any thread. A handle that was cloned after the function started closing always returns `ThreadsafeFunctionError::Closing`.
+	pub fn call(
+		&self,
+		value: T,
+		mode: ThreadsafeFunctionCallMode,
+	) -> Result<(), ThreadsafeFunctionError<T>> {
+		if !self.acquired {
+			return Err(ThreadsafeFunctionError::Closing(value));
+		}
+		let mode = match mode {
+			ThreadsafeFunctionCallMode::Blocking => {
+				napi_threadsafe_function_call_mode::napi_tsfn_blocking
+			}
+			ThreadsafeFunctionCallMode::NonBlocking => {
+				napi_threadsafe_function_call_mode::napi_tsfn_nonblocking
+			}
+		};
+		let data = Box::into_raw(Box::new(value));
+		let status = unsafe { napi_call_threadsafe_function(self.raw, data as *mut c_void, mode) };
+		match status {
+			napi_status::napi_ok => Ok(()),
+			napi_status::napi_queue_full => {
+				let value = unsafe { *Box::from_raw(data) };
+				Err(ThreadsafeFunctionError::QueueFull(value))
+			}
+			napi_status::napi_closing => {
+				let value = unsafe { *Box::from_raw(data) };
+				Err(ThreadsafeFunctionError::Closing(value))
+			}
+			status => {
+				drop(unsafe { Box::from_raw(data) });
+				Err(ThreadsafeFunctionError::Other(Error::node_api(
+					status,
+					"failed to call threadsafe function",
+				)))
+			}
+		}
+	}
+
+	/// Keep the event loop running while this thread-safe function exists. This must be called on the main thread.
+	pub fn refer(&self, env: Env) -> Result<()> {
+		if !self.acquired {
+			return Err(Error::message("the threadsafe function is closing"));
+		}
+		unsafe {
+			let status = napi_ref_threadsafe_function(env.raw(), self.raw);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+		}
+		Ok(())
+	}
+
+	/// Allow the event loop to exit while this thread-safe function exists. This must be called on the main thread.
+	pub fn unref(&self, env: Env) -> Result<()> {
+		if !self.acquired {
+			return Err(Error::message("the threadsafe function is closing"));
+		}
+		unsafe {
+			let status = napi_unref_threadsafe_function(env.raw(), self.raw);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+		}
+		Ok(())
+	}
+}
+
+impl<T> Clone for ThreadsafeFunction<T> {
+	fn clone(&self) -> ThreadsafeFunction<T> {
+		let status = unsafe { napi_acquire_threadsafe_function(self.raw) };
+		ThreadsafeFunction {
+			raw: self.raw,
+			acquired: status == napi_status::napi_ok,
+			_marker: PhantomData,
+		}
+	}
+}
+
+impl<T> Drop for ThreadsafeFunction<T> {
+	fn drop(&mut self) {
+		if self.acquired {
+			unsafe {
+				napi_release_threadsafe_function(
+					self.raw,
+					napi_threadsafe_function_release_mode::napi_tsfn_release,
+				);
+			}
+		}
+	}
+}
+
+impl<T> std::fmt::Debug for ThreadsafeFunctionError<T> {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			ThreadsafeFunctionError::QueueFull(_) => write!(f, "QueueFull(..)"),
+			ThreadsafeFunctionError::Closing(_) => write!(f, "Closing(..)"),
+			ThreadsafeFunctionError::Other(error) => write!(f, "Other({:?})", error),
+		}
+	}
+}
+
+impl<T> std::fmt::Display for ThreadsafeFunctionError<T> {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			ThreadsafeFunctionError::QueueFull(_) => {
+				write!(f, "the threadsafe function queue is full")
+			}
+			ThreadsafeFunctionError::Closing(_) => write!(f, "the threadsafe function is closing"),
+			ThreadsafeFunctionError::Other(error) => write!(f, "{}", error),
+		}
+	}
+}
+
+impl<T> std::error::Error for ThreadsafeFunctionError<T> {}
+
+unsafe extern "C" fn call_js_trampoline<T>(
+	env: napi_env,
+	js_callback: napi_value,
+	context: *mut c_void,
+	data: *mut c_void,
+) {
+	let value = *Box::from_raw(data as *mut T);
+	if env.is_null() {
+		return;
+	}
+	let env = Env::from_raw(env);
+	let call_js = &*(context as *const Box<CallJs<T>>);
+	run_callback(env, || {
+		let func = if js_callback.is_null() {
+			None
+		} else {
+			Some(Value::from_raw(env, js_callback).as_function()?)
+		};
+		call_js(env, func, value)?;
+		Ok(Undefined::new(env)?.value())
+	});
+}
+
+unsafe extern "C" fn finalize_call_js<T>(_env: napi_env, data: *mut c_void, _hint: *mut c_void) {
+	drop(Box::from_raw(data as *mut Box<CallJs<T>>));
+}
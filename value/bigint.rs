@@ -1,4 +1,12 @@
-use crate::{Env, Value};
+use crate::{
+	sys::{
+		napi_create_bigint_int64, napi_create_bigint_uint64, napi_create_bigint_words,
+		napi_get_value_bigint_int64, napi_get_value_bigint_uint64, napi_get_value_bigint_words,
+		napi_status,
+	},
+	Env, Error, Result, Value,
+};
+use std::{mem::MaybeUninit, os::raw::c_int};
 
 pub struct BigInt<'a>(Value<'a>);
 
@@ -14,4 +22,127 @@ impl<'a> BigInt<'a> {
 	pub fn env(&self) -> Env<'a> {
 		self.0.env()
 	}
+
+	pub fn from_i64(env: Env<'a>, value: i64) -> Result<BigInt<'a>> {
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_create_bigint_int64(env.raw(), value, result.as_mut_ptr());
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			result.assume_init()
+		};
+		let value = Value::from_raw(env, value);
+		let value = BigInt(value);
+		Ok(value)
+	}
+
+	pub fn from_u64(env: Env<'a>, value: u64) -> Result<BigInt<'a>> {
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_create_bigint_uint64(env.raw(), value, result.as_mut_ptr());
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			result.assume_init()
+		};
+		let value = Value::from_raw(env, value);
+		let value = BigInt(value);
+		Ok(value)
+	}
+
+	/// Create a `BigInt` from its sign and its magnitude as 64-bit words, least significant word first.
+	pub fn from_words(env: Env<'a>, negative: bool, words: &[u64]) -> Result<BigInt<'a>> {
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_create_bigint_words(
+				env.raw(),
+				negative as c_int,
+				words.len(),
+				words.as_ptr(),
+				result.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			result.assume_init()
+		};
+		let value = Value::from_raw(env, value);
+		let value = BigInt(value);
+		Ok(value)
+	}
+
+	/// Get the value as an `i64`. The second element of the tuple is `false` if the value was truncated.
+	pub fn get_i64(&self) -> Result<(i64, bool)> {
+		let (value, lossless) = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let mut lossless = MaybeUninit::uninit();
+			let status = napi_get_value_bigint_int64(
+				self.env().raw(),
+				self.value().raw(),
+				result.as_mut_ptr(),
+				lossless.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+			(result.assume_init(), lossless.assume_init())
+		};
+		Ok((value, lossless))
+	}
+
+	/// Get the value as a `u64`. The second element of the tuple is `false` if the value was truncated.
+	pub fn get_u64(&self) -> Result<(u64, bool)> {
+		let (value, lossless) = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let mut lossless = MaybeUninit::uninit();
+			let status = napi_get_value_bigint_uint64(
+				self.env().raw(),
+				self.value().raw(),
+				result.as_mut_ptr(),
+				lossless.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+			(result.assume_init(), lossless.assume_init())
+		};
+		Ok((value, lossless))
+	}
+
+	/// Get the sign and the magnitude as 64-bit words, least significant word first. The conversion is always lossless.
+	pub fn get_words(&self) -> Result<(bool, Vec<u64>)> {
+		let word_count = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_get_value_bigint_words(
+				self.env().raw(),
+				self.value().raw(),
+				std::ptr::null_mut(),
+				result.as_mut_ptr(),
+				std::ptr::null_mut(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+			result.assume_init()
+		};
+		let mut words: Vec<u64> = Vec::with_capacity(word_count);
+		let (sign_bit, word_count) = unsafe {
+			let mut sign_bit = MaybeUninit::uninit();
+			let mut word_count = word_count;
+			let status = napi_get_value_bigint_words(
+				self.env().raw(),
+				self.value().raw(),
+				sign_bit.as_mut_ptr(),
+				&mut word_count,
+				words.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+			(sign_bit.assume_init(), word_count)
+		};
+		unsafe { words.set_len(word_count) };
+		Ok((sign_bit != 0, words))
+	}
 }
@@ -1,4 +1,11 @@
-use crate::{Env, Value};
+use crate::{
+	sys::{
+		napi_create_buffer_copy, napi_create_external_buffer, napi_env, napi_get_buffer_info,
+		napi_status,
+	},
+	Env, Error, Result, Value,
+};
+use std::{ffi::c_void, mem::MaybeUninit};
 
 pub struct Buffer<'a>(Value<'a>);
 
@@ -14,4 +21,111 @@ impl<'a> Buffer<'a> {
 	pub fn env(&self) -> Env<'a> {
 		self.0.env()
 	}
+
+	/// Create a `Buffer` with a copy of `bytes`.
+	pub fn new(env: Env<'a>, bytes: &[u8]) -> Result<Buffer<'a>> {
+		let value = unsafe {
+			let mut data = MaybeUninit::uninit();
+			let mut result = MaybeUninit::uninit();
+			let status = napi_create_buffer_copy(
+				env.raw(),
+				bytes.len(),
+				bytes.as_ptr() as *const c_void,
+				data.as_mut_ptr(),
+				result.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			result.assume_init()
+		};
+		let value = Value::from_raw(env, value);
+		let value = Buffer(value);
+		Ok(value)
+	}
+
+	/// Create a `Buffer` that takes ownership of `bytes` without copying them. The `Vec` is dropped when the `Buffer` is garbage collected.
+	pub fn from_vec(env: Env<'a>, bytes: Vec<u8>) -> Result<Buffer<'a>> {
+		let mut bytes = Box::new(bytes);
+		let data = bytes.as_mut_ptr();
+		let len = bytes.len();
+		let bytes = Box::into_raw(bytes);
+		unsafe extern "C" fn finalize(_env: napi_env, _data: *mut c_void, hint: *mut c_void) {
+			drop(Box::from_raw(hint as *mut Vec<u8>));
+		}
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_create_external_buffer(
+				env.raw(),
+				len,
+				data as *mut c_void,
+				Some(finalize),
+				bytes as *mut c_void,
+				result.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				drop(Box::from_raw(bytes));
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			result.assume_init()
+		};
+		let value = Value::from_raw(env, value);
+		let value = Buffer(value);
+		Ok(value)
+	}
+
+	fn info(&self) -> Result<(*mut u8, usize)> {
+		let (data, len) = unsafe {
+			let mut data = MaybeUninit::uninit();
+			let mut len = MaybeUninit::uninit();
+			let status = napi_get_buffer_info(
+				self.env().raw(),
+				self.value().raw(),
+				data.as_mut_ptr(),
+				len.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+			(data.assume_init(), len.assume_init())
+		};
+		Ok((data as *mut u8, len))
+	}
+
+	pub fn len(&self) -> Result<usize> {
+		let (_, len) = self.info()?;
+		Ok(len)
+	}
+
+	pub fn is_empty(&self) -> Result<bool> {
+		Ok(self.len()? == 0)
+	}
+
+	pub fn as_slice(&self) -> Result<&[u8]> {
+		let (data, len) = self.info()?;
+		if len == 0 {
+			return Ok(&[]);
+		}
+		let slice = unsafe { std::slice::from_raw_parts(data, len) };
+		Ok(slice)
+	}
+
+	pub fn as_mut_slice(&mut self) -> Result<&mut [u8]> {
+		let (data, len) = self.info()?;
+		if len == 0 {
+			return Ok(&mut []);
+		}
+		let slice = unsafe { std::slice::from_raw_parts_mut(data, len) };
+		Ok(slice)
+	}
+
+	/// Copy `bytes` into the `Buffer`. The length of `bytes` must equal the length of the `Buffer`.
+	pub fn copy_from(&mut self, bytes: &[u8]) -> Result<()> {
+		let slice = self.as_mut_slice()?;
+		if slice.len() != bytes.len() {
+			return Err(Error::message("buffer length does not match source length"));
+		}
+		slice.copy_from_slice(bytes);
+		Ok(())
+	}
 }
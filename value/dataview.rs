@@ -1,7 +1,32 @@
-use crate::{Env, Value};
+use crate::{
+	sys::{napi_create_dataview, napi_get_dataview_info, napi_status, napi_value},
+	ArrayBuffer, Env, Error, Result, Value,
+};
+use std::{convert::TryInto, ffi::c_void, mem::MaybeUninit};
 
 pub struct DataView<'a>(Value<'a>);
 
+pub struct DataViewInfo<'a> {
+	pub byte_length: usize,
+	pub arraybuffer: ArrayBuffer<'a>,
+	pub byte_offset: usize,
+}
+
+macro_rules! impl_get_set {
+	($ty:ty, $get:ident, $set:ident, $from_bytes:ident, $to_bytes:ident) => {
+		pub fn $get(&self, byte_offset: usize) -> Result<$ty> {
+			let bytes = self.bytes(byte_offset, std::mem::size_of::<$ty>())?;
+			Ok(<$ty>::$from_bytes(bytes.try_into().unwrap()))
+		}
+
+		pub fn $set(&mut self, byte_offset: usize, value: $ty) -> Result<()> {
+			let bytes = self.bytes_mut(byte_offset, std::mem::size_of::<$ty>())?;
+			bytes.copy_from_slice(&value.$to_bytes());
+			Ok(())
+		}
+	};
+}
+
 impl<'a> DataView<'a> {
 	pub(crate) fn from_value(value: Value) -> DataView {
 		DataView(value)
@@ -14,4 +39,127 @@ impl<'a> DataView<'a> {
 	pub fn env(&self) -> Env<'a> {
 		self.0.env()
 	}
+
+	/// Create a `DataView` over `byte_length` bytes of `arraybuffer`, starting at `byte_offset`.
+	pub fn new(
+		env: Env<'a>,
+		arraybuffer: &ArrayBuffer<'a>,
+		byte_offset: usize,
+		byte_length: usize,
+	) -> Result<DataView<'a>> {
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_create_dataview(
+				env.raw(),
+				byte_length,
+				arraybuffer.value().raw(),
+				byte_offset,
+				result.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			result.assume_init()
+		};
+		let value = Value::from_raw(env, value);
+		let value = DataView(value);
+		Ok(value)
+	}
+
+	fn raw_info(&self) -> Result<(usize, *mut c_void, napi_value, usize)> {
+		unsafe {
+			let mut byte_length = MaybeUninit::uninit();
+			let mut data = MaybeUninit::uninit();
+			let mut arraybuffer = MaybeUninit::uninit();
+			let mut byte_offset = MaybeUninit::uninit();
+			let status = napi_get_dataview_info(
+				self.env().raw(),
+				self.value().raw(),
+				byte_length.as_mut_ptr(),
+				data.as_mut_ptr(),
+				arraybuffer.as_mut_ptr(),
+				byte_offset.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+			Ok((
+				byte_length.assume_init(),
+				data.assume_init(),
+				arraybuffer.assume_init(),
+				byte_offset.assume_init(),
+			))
+		}
+	}
+
+	pub fn info(&self) -> Result<DataViewInfo<'a>> {
+		let (byte_length, _, arraybuffer, byte_offset) = self.raw_info()?;
+		let arraybuffer = ArrayBuffer::from_value(Value::from_raw(self.env(), arraybuffer));
+		Ok(DataViewInfo {
+			byte_length,
+			arraybuffer,
+			byte_offset,
+		})
+	}
+
+	pub fn byte_length(&self) -> Result<usize> {
+		let (byte_length, _, _, _) = self.raw_info()?;
+		Ok(byte_length)
+	}
+
+	/// Get the bytes of the backing `ArrayBuffer` that this `DataView` covers.
+	pub fn as_slice(&self) -> Result<&[u8]> {
+		let (byte_length, data, _, _) = self.raw_info()?;
+		if byte_length == 0 {
+			return Ok(&[]);
+		}
+		let slice = unsafe { std::slice::from_raw_parts(data as *const u8, byte_length) };
+		Ok(slice)
+	}
+
+	pub fn as_mut_slice(&mut self) -> Result<&mut [u8]> {
+		let (byte_length, data, _, _) = self.raw_info()?;
+		if byte_length == 0 {
+			return Ok(&mut []);
+		}
+		let slice = unsafe { std::slice::from_raw_parts_mut(data as *mut u8, byte_length) };
+		Ok(slice)
+	}
+
+	fn bytes(&self, byte_offset: usize, len: usize) -> Result<&[u8]> {
+		let slice = self.as_slice()?;
+		let end = byte_offset
+			.checked_add(len)
+			.filter(|end| *end <= slice.len())
+			.ok_or_else(|| Error::range_error("dataview offset out of bounds"))?;
+		Ok(&slice[byte_offset..end])
+	}
+
+	fn bytes_mut(&mut self, byte_offset: usize, len: usize) -> Result<&mut [u8]> {
+		let slice = self.as_mut_slice()?;
+		let end = byte_offset
+			.checked_add(len)
+			.filter(|end| *end <= slice.len())
+			.ok_or_else(|| Error::range_error("dataview offset out of bounds"))?;
+		Ok(&mut slice[byte_offset..end])
+	}
+
+	impl_get_set!(u8, get_u8, set_u8, from_le_bytes, to_le_bytes);
+	impl_get_set!(i8, get_i8, set_i8, from_le_bytes, to_le_bytes);
+	impl_get_set!(u16, get_u16_le, set_u16_le, from_le_bytes, to_le_bytes);
+	impl_get_set!(u16, get_u16_be, set_u16_be, from_be_bytes, to_be_bytes);
+	impl_get_set!(i16, get_i16_le, set_i16_le, from_le_bytes, to_le_bytes);
+	impl_get_set!(i16, get_i16_be, set_i16_be, from_be_bytes, to_be_bytes);
+	impl_get_set!(u32, get_u32_le, set_u32_le, from_le_bytes, to_le_bytes);
+	impl_get_set!(u32, get_u32_be, set_u32_be, from_be_bytes, to_be_bytes);
+	impl_get_set!(i32, get_i32_le, set_i32_le, from_le_bytes, to_le_bytes);
+	impl_get_set!(i32, get_i32_be, set_i32_be, from_be_bytes, to_be_bytes);
+	impl_get_set!(u64, get_u64_le, set_u64_le, from_le_bytes, to_le_bytes);
+	impl_get_set!(u64, get_u64_be, set_u64_be, from_be_bytes, to_be_bytes);
+	impl_get_set!(i64, get_i64_le, set_i64_le, from_le_bytes, to_le_bytes);
+	impl_get_set!(i64, get_i64_be, set_i64_be, from_be_bytes, to_be_bytes);
+	impl_get_set!(f32, get_f32_le, set_f32_le, from_le_bytes, to_le_bytes);
+	impl_get_set!(f32, get_f32_be, set_f32_be, from_be_bytes, to_be_bytes);
+	impl_get_set!(f64, get_f64_le, set_f64_le, from_le_bytes, to_le_bytes);
+	impl_get_set!(f64, get_f64_be, set_f64_be, from_be_bytes, to_be_bytes);
 }
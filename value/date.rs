@@ -1,4 +1,8 @@
-use crate::{Env, Value};
+use crate::{
+	sys::{napi_create_date, napi_get_date_value, napi_status},
+	Env, Error, Result, Value,
+};
+use std::mem::MaybeUninit;
 
 pub struct Date<'a>(Value<'a>);
 
@@ -14,4 +18,33 @@ impl<'a> Date<'a> {
 	pub fn env(&self) -> Env<'a> {
 		self.0.env()
 	}
+
+	/// Create a `Date` from the number of milliseconds since the Unix epoch.
+	pub fn new(env: Env<'a>, value: f64) -> Result<Date<'a>> {
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_create_date(env.raw(), value, result.as_mut_ptr());
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			result.assume_init()
+		};
+		let value = Value::from_raw(env, value);
+		let value = Date(value);
+		Ok(value)
+	}
+
+	/// Get the number of milliseconds since the Unix epoch. This is `NaN` for an invalid `Date`.
+	pub fn get(&self) -> Result<f64> {
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status =
+				napi_get_date_value(self.env().raw(), self.value().raw(), result.as_mut_ptr());
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+			result.assume_init()
+		};
+		Ok(value)
+	}
 }
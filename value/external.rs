@@ -23,7 +23,7 @@ impl<'a, T> External<'a, T> {
 		let value = Box::new(value);
 		let value = Box::into_raw(value);
 		unsafe extern "C" fn finalize<T>(_env: napi_env, data: *mut c_void, _hint: *mut c_void) {
-			Box::from_raw(data as *mut T);
+			drop(Box::from_raw(data as *mut T));
 		}
 		let value = unsafe {
 			let mut result = MaybeUninit::uninit();
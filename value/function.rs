@@ -1,10 +1,14 @@
 use node_api_system::napi_status;
 
 use crate::{
-	sys::{napi_callback_info, napi_create_function, napi_env, napi_value},
-	Env, Error, Result, Value,
+	callback::{call_callback, finalize_callback, Callback},
+	sys::{
+		napi_add_finalizer, napi_call_function, napi_callback_info, napi_create_function, napi_env,
+		napi_new_instance, napi_value,
+	},
+	CallContext, Env, Error, Result, Value,
 };
-use std::{mem::MaybeUninit, os::raw::c_char};
+use std::{ffi::c_void, mem::MaybeUninit, os::raw::c_char};
 
 pub struct Function<'a>(Value<'a>);
 
@@ -45,4 +49,88 @@ impl<'a> Function<'a> {
 		let value = Function(value);
 		Ok(value)
 	}
+
+	/// Create a function that calls `f`. The closure is dropped when the function is garbage collected.
+	pub fn from_closure<F>(env: Env<'a>, name: &str, f: F) -> Result<Function<'a>>
+	where
+		F: for<'b> Fn(Env<'b>, CallContext<'b>) -> Result<Value<'b>> + 'static,
+	{
+		let callback: Box<Box<Callback>> = Box::new(Box::new(f));
+		let callback = Box::into_raw(callback);
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_create_function(
+				env.raw(),
+				name.as_ptr() as *const c_char,
+				name.len(),
+				Some(call_callback),
+				callback as *mut c_void,
+				result.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				drop(Box::from_raw(callback));
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			let value = result.assume_init();
+			let status = napi_add_finalizer(
+				env.raw(),
+				value,
+				callback as *mut c_void,
+				Some(finalize_callback),
+				std::ptr::null_mut(),
+				std::ptr::null_mut(),
+			);
+			if status != napi_status::napi_ok {
+				drop(Box::from_raw(callback));
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			value
+		};
+		let value = Value::from_raw(env, value);
+		let value = Function(value);
+		Ok(value)
+	}
+
+	/// Call the function with `this` as its receiver. If the function throws, the exception is cleared and returned as `Error::JsException`.
+	pub fn call(&self, this: impl Into<Value<'a>>, args: &[Value<'a>]) -> Result<Value<'a>> {
+		let args = args.iter().map(|arg| arg.raw()).collect::<Vec<_>>();
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_call_function(
+				self.env().raw(),
+				this.into().raw(),
+				self.value().raw(),
+				args.len(),
+				args.as_ptr(),
+				result.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+			result.assume_init()
+		};
+		let value = Value::from_raw(self.env(), value);
+		Ok(value)
+	}
+
+	/// Call the function as a constructor, as `new` does. If the constructor throws, the exception is cleared and returned as `Error::JsException`.
+	pub fn construct(&self, args: &[Value<'a>]) -> Result<Value<'a>> {
+		let args = args.iter().map(|arg| arg.raw()).collect::<Vec<_>>();
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_new_instance(
+				self.env().raw(),
+				self.value().raw(),
+				args.len(),
+				args.as_ptr(),
+				result.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+			result.assume_init()
+		};
+		let value = Value::from_raw(self.env(), value);
+		Ok(value)
+	}
 }
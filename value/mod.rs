@@ -1,7 +1,7 @@
 use crate::{
 	sys::{
 		napi_is_array, napi_is_arraybuffer, napi_is_buffer, napi_is_dataview, napi_is_date,
-		napi_is_typedarray, napi_status, napi_typeof, napi_value, napi_valuetype,
+		napi_is_promise, napi_is_typedarray, napi_status, napi_typeof, napi_value, napi_valuetype,
 	},
 	Env, Error, Result,
 };
@@ -19,6 +19,7 @@ pub mod function;
 pub mod null;
 pub mod number;
 pub mod object;
+pub mod promise;
 pub mod string;
 pub mod symbol;
 pub mod typedarray;
@@ -27,8 +28,8 @@ pub mod undefined;
 pub use self::{
 	array::Array, arraybuffer::ArrayBuffer, bigint::BigInt, boolean::Boolean, buffer::Buffer,
 	dataview::DataView, date::Date, external::External, function::Function, null::Null,
-	number::Number, object::Object, string::String, symbol::Symbol, typedarray::TypedArray,
-	undefined::Undefined,
+	number::Number, object::Object, promise::Promise, string::String, symbol::Symbol,
+	typedarray::TypedArray, undefined::Undefined,
 };
 
 #[derive(Clone, Copy)]
@@ -50,7 +51,7 @@ impl<'a> Value<'a> {
 		self.value
 	}
 
-	fn value_type(&self) -> Result<napi_valuetype> {
+	pub(crate) fn value_type(&self) -> Result<napi_valuetype> {
 		let value = unsafe {
 			let mut result = MaybeUninit::uninit();
 			let status = napi_typeof(self.env().raw(), self.raw(), result.as_mut_ptr());
@@ -241,6 +242,25 @@ impl<'a> Value<'a> {
 		}
 	}
 
+	pub fn is_promise(&self) -> Result<bool> {
+		unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_is_promise(self.env().raw(), self.raw(), result.as_mut_ptr());
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+			Ok(result.assume_init())
+		}
+	}
+
+	pub fn as_promise(&self) -> Result<Promise<'a>> {
+		if self.is_promise()? {
+			Ok(Promise::from_value(*self))
+		} else {
+			Err(Error::message("could not cast value to promise"))
+		}
+	}
+
 	pub fn is_string(&self) -> Result<bool> {
 		Ok(self.value_type()? == napi_valuetype::napi_string)
 	}
@@ -372,6 +392,12 @@ impl<'a> From<Object<'a>> for Value<'a> {
 	}
 }
 
+impl<'a> From<Promise<'a>> for Value<'a> {
+	fn from(value: Promise<'a>) -> Value<'a> {
+		value.value()
+	}
+}
+
 impl<'a> From<String<'a>> for Value<'a> {
 	fn from(value: String<'a>) -> Value<'a> {
 		value.value()
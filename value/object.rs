@@ -1,14 +1,57 @@
 use crate::{
+	property::RawPropertyDescriptors,
 	sys::{
-		napi_create_object, napi_get_property, napi_get_property_names, napi_set_property,
-		napi_status,
+		napi_create_object, napi_define_properties, napi_delete_property, napi_env,
+		napi_get_all_property_names, napi_get_named_property, napi_get_property,
+		napi_get_property_names, napi_has_named_property, napi_has_own_property, napi_has_property,
+		napi_key_collection_mode, napi_key_conversion, napi_key_filter, napi_remove_wrap,
+		napi_set_named_property, napi_set_property, napi_status, napi_unwrap, napi_wrap,
 	},
-	Array, Env, Error, Result, Value,
+	Array, Env, Error, FromNodeAPI, PropertyDescriptor, Result, Value,
 };
-use std::mem::MaybeUninit;
+use std::{
+	any::Any,
+	cell::{Ref, RefCell, RefMut},
+	ffi::{c_void, CString},
+	mem::MaybeUninit,
+};
+
+type Wrapped = RefCell<Box<dyn Any>>;
 
 pub struct Object<'a>(pub(crate) Value<'a>);
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KeyCollectionMode {
+	IncludePrototypes,
+	OwnOnly,
+}
+
+/// Which keys `Object::keys_with` returns. Combine filters with `|`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KeyFilter(napi_key_filter);
+
+impl KeyFilter {
+	pub const ALL_PROPERTIES: KeyFilter = KeyFilter(napi_key_filter::napi_key_all_properties);
+	pub const WRITABLE: KeyFilter = KeyFilter(napi_key_filter::napi_key_writable);
+	pub const ENUMERABLE: KeyFilter = KeyFilter(napi_key_filter::napi_key_enumerable);
+	pub const CONFIGURABLE: KeyFilter = KeyFilter(napi_key_filter::napi_key_configurable);
+	pub const SKIP_STRINGS: KeyFilter = KeyFilter(napi_key_filter::napi_key_skip_strings);
+	pub const SKIP_SYMBOLS: KeyFilter = KeyFilter(napi_key_filter::napi_key_skip_symbols);
+}
+
+impl std::ops::BitOr for KeyFilter {
+	type Output = KeyFilter;
+	fn bitor(self, other: KeyFilter) -> KeyFilter {
+		KeyFilter(self.0 | other.0)
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KeyConversion {
+	KeepNumbers,
+	NumbersToStrings,
+}
+
 impl<'a> Object<'a> {
 	pub(crate) fn from_value(value: Value) -> Object {
 		Object(value)
@@ -53,6 +96,42 @@ impl<'a> Object<'a> {
 		Ok(properties)
 	}
 
+	/// Get the keys of the object, selected by `mode` and `filter`. Unlike `properties`, this can return own keys only and symbol keys.
+	pub fn keys_with(
+		&self,
+		mode: KeyCollectionMode,
+		filter: KeyFilter,
+		conversion: KeyConversion,
+	) -> Result<Array<'a>> {
+		let mode = match mode {
+			KeyCollectionMode::IncludePrototypes => {
+				napi_key_collection_mode::napi_key_include_prototypes
+			}
+			KeyCollectionMode::OwnOnly => napi_key_collection_mode::napi_key_own_only,
+		};
+		let conversion = match conversion {
+			KeyConversion::KeepNumbers => napi_key_conversion::napi_key_keep_numbers,
+			KeyConversion::NumbersToStrings => napi_key_conversion::napi_key_numbers_to_strings,
+		};
+		let keys = unsafe {
+			let mut keys = MaybeUninit::uninit();
+			let status = napi_get_all_property_names(
+				self.env().raw(),
+				self.value().raw(),
+				mode,
+				filter.0,
+				conversion,
+				keys.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+			keys.assume_init()
+		};
+		let keys = Array::from_value(Value::from_raw(self.env(), keys));
+		Ok(keys)
+	}
+
 	pub fn size(&self) -> Result<usize> {
 		let properties = self.properties()?;
 		let len = properties.size()?;
@@ -91,4 +170,235 @@ impl<'a> Object<'a> {
 		};
 		Ok(())
 	}
+
+	pub fn has(&self, key: impl Into<Value<'a>>) -> Result<bool> {
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_has_property(
+				self.env().raw(),
+				self.value().raw(),
+				key.into().raw(),
+				result.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+			result.assume_init()
+		};
+		Ok(value)
+	}
+
+	/// Check whether the object has an own property with `key`, which must be a string or a symbol.
+	pub fn has_own(&self, key: impl Into<Value<'a>>) -> Result<bool> {
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_has_own_property(
+				self.env().raw(),
+				self.value().raw(),
+				key.into().raw(),
+				result.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+			result.assume_init()
+		};
+		Ok(value)
+	}
+
+	/// Delete the property with `key`. Returns `false` if the property could not be deleted.
+	pub fn delete(&mut self, key: impl Into<Value<'a>>) -> Result<bool> {
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_delete_property(
+				self.env().raw(),
+				self.value().raw(),
+				key.into().raw(),
+				result.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+			result.assume_init()
+		};
+		Ok(value)
+	}
+
+	pub fn get_as<T>(&self, key: impl Into<Value<'a>>) -> Result<T>
+	where
+		T: FromNodeAPI<'a>,
+	{
+		T::from_node_api(self.get(key)?)
+	}
+
+	pub fn has_named(&self, name: &str) -> Result<bool> {
+		let name = property_name(name)?;
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_has_named_property(
+				self.env().raw(),
+				self.value().raw(),
+				name.as_ptr(),
+				result.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+			result.assume_init()
+		};
+		Ok(value)
+	}
+
+	pub fn get_named(&self, name: &str) -> Result<Value<'a>> {
+		let name = property_name(name)?;
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_get_named_property(
+				self.env().raw(),
+				self.value().raw(),
+				name.as_ptr(),
+				result.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+			result.assume_init()
+		};
+		let value = Value::from_raw(self.env(), value);
+		Ok(value)
+	}
+
+	pub fn get_named_as<T>(&self, name: &str) -> Result<T>
+	where
+		T: FromNodeAPI<'a>,
+	{
+		T::from_node_api(self.get_named(name)?)
+	}
+
+	pub fn set_named(&mut self, name: &str, value: impl Into<Value<'a>>) -> Result<()> {
+		let name = property_name(name)?;
+		unsafe {
+			let status = napi_set_named_property(
+				self.env().raw(),
+				self.value().raw(),
+				name.as_ptr(),
+				value.into().raw(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+		};
+		Ok(())
+	}
+
+	/// Define all of `properties` in a single call.
+	pub fn define_properties(&mut self, properties: &[PropertyDescriptor<'a>]) -> Result<()> {
+		let properties = RawPropertyDescriptors::new(properties)?;
+		properties.attach(self.env(), self.value().raw())?;
+		unsafe {
+			let status = napi_define_properties(
+				self.env().raw(),
+				self.value().raw(),
+				properties.descriptors().len(),
+				properties.descriptors().as_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+		}
+		Ok(())
+	}
+
+	/// Attach `value` to the object. The value is dropped when the object is garbage collected.
+	pub fn wrap<T: 'static>(&mut self, value: T) -> Result<()> {
+		let wrapped: Box<Wrapped> = Box::new(RefCell::new(Box::new(value)));
+		let wrapped = Box::into_raw(wrapped);
+		unsafe extern "C" fn finalize(_env: napi_env, data: *mut c_void, _hint: *mut c_void) {
+			drop(Box::from_raw(data as *mut Wrapped));
+		}
+		unsafe {
+			let status = napi_wrap(
+				self.env().raw(),
+				self.value().raw(),
+				wrapped as *mut c_void,
+				Some(finalize),
+				std::ptr::null_mut(),
+				std::ptr::null_mut(),
+			);
+			if status != napi_status::napi_ok {
+				drop(Box::from_raw(wrapped));
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+		}
+		Ok(())
+	}
+
+	fn wrapped(&self) -> Result<&'a Wrapped> {
+		let wrapped = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_unwrap(self.env().raw(), self.value().raw(), result.as_mut_ptr());
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+			result.assume_init()
+		};
+		let wrapped = unsafe { &*(wrapped as *const Wrapped) };
+		Ok(wrapped)
+	}
+
+	/// Borrow the value attached with `wrap`.
+	pub fn unwrap<T: 'static>(&self) -> Result<Ref<'a, T>> {
+		let wrapped = self
+			.wrapped()?
+			.try_borrow()
+			.map_err(|_| Error::message("wrapped value is already mutably borrowed"))?;
+		if !wrapped.is::<T>() {
+			return Err(Error::message("wrapped value has a different type"));
+		}
+		Ok(Ref::map(wrapped, |wrapped| {
+			wrapped.downcast_ref::<T>().unwrap()
+		}))
+	}
+
+	/// Mutably borrow the value attached with `wrap`.
+	pub fn unwrap_mut<T: 'static>(&self) -> Result<RefMut<'a, T>> {
+		let wrapped = self
+			.wrapped()?
+			.try_borrow_mut()
+			.map_err(|_| Error::message("wrapped value is already borrowed"))?;
+		if !wrapped.is::<T>() {
+			return Err(Error::message("wrapped value has a different type"));
+		}
+		Ok(RefMut::map(wrapped, |wrapped| {
+			wrapped.downcast_mut::<T>().unwrap()
+		}))
+	}
+
+	/// Detach the value attached with `wrap` and return it.
+	pub fn remove_wrap<T: 'static>(&mut self) -> Result<T> {
+		{
+			let wrapped = self
+				.wrapped()?
+				.try_borrow_mut()
+				.map_err(|_| Error::message("wrapped value is already borrowed"))?;
+			if !wrapped.is::<T>() {
+				return Err(Error::message("wrapped value has a different type"));
+			}
+		}
+		let wrapped = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status =
+				napi_remove_wrap(self.env().raw(), self.value().raw(), result.as_mut_ptr());
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+			Box::from_raw(result.assume_init() as *mut Wrapped)
+		};
+		let value = wrapped.into_inner().downcast::<T>().unwrap();
+		Ok(*value)
+	}
+}
+
+fn property_name(name: &str) -> Result<CString> {
+	CString::new(name).map_err(|_| Error::message("property name must not contain a nul byte"))
 }
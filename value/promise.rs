@@ -0,0 +1,92 @@
+use crate::{
+	sys::{
+		napi_create_promise, napi_deferred, napi_env, napi_reject_deferred, napi_resolve_deferred,
+		napi_status, napi_value,
+	},
+	Env, Error, IntoNodeApi, Result, Value,
+};
+use std::mem::MaybeUninit;
+
+pub struct Promise<'a>(Value<'a>);
+
+/// The resolve and reject half of a `Promise` created with `Promise::new`. If it is dropped without being resolved or rejected, the promise stays pending forever.
+pub struct Deferred {
+	env: napi_env,
+	raw: napi_deferred,
+}
+
+impl<'a> Promise<'a> {
+	pub(crate) fn from_value(value: Value) -> Promise {
+		Promise(value)
+	}
+
+	pub fn value(&self) -> Value<'a> {
+		self.0
+	}
+
+	pub fn env(&self) -> Env<'a> {
+		self.0.env()
+	}
+
+	/// Create a pending promise along with the `Deferred` that settles it.
+	pub fn new(env: Env<'a>) -> Result<(Deferred, Promise<'a>)> {
+		let (deferred, value) = unsafe {
+			let mut deferred = MaybeUninit::uninit();
+			let mut promise = MaybeUninit::uninit();
+			let status =
+				napi_create_promise(env.raw(), deferred.as_mut_ptr(), promise.as_mut_ptr());
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			(deferred.assume_init(), promise.assume_init())
+		};
+		let deferred = Deferred {
+			env: env.raw(),
+			raw: deferred,
+		};
+		let value = Value::from_raw(env, value);
+		let value = Promise(value);
+		Ok((deferred, value))
+	}
+}
+
+impl Deferred {
+	/// Resolve the promise with `value`. If `value` cannot be converted, the promise is rejected with the conversion error instead.
+	pub fn resolve<'a, T>(self, value: T) -> Result<()>
+	where
+		T: IntoNodeApi<'a>,
+	{
+		let env = Env::from_raw(self.env);
+		match value.into_node_api(env) {
+			Ok(value) => self.settle(value, napi_resolve_deferred),
+			Err(error) => self.reject(error),
+		}
+	}
+
+	/// Reject the promise with `error`, which is typically an `Error`.
+	pub fn reject<'a, T>(self, error: T) -> Result<()>
+	where
+		T: IntoNodeApi<'a>,
+	{
+		let env = Env::from_raw(self.env);
+		let error = match error.into_node_api(env) {
+			Ok(error) => error,
+			Err(error) => error.into_node_api(env)?,
+		};
+		self.settle(error, napi_reject_deferred)
+	}
+
+	fn settle(
+		self,
+		value: Value,
+		f: unsafe extern "C" fn(napi_env, napi_deferred, napi_value) -> napi_status,
+	) -> Result<()> {
+		unsafe {
+			let status = f(self.env, self.raw, value.raw());
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env, status));
+			}
+		}
+		Ok(())
+	}
+}
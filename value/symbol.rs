@@ -1,4 +1,8 @@
-use crate::{Env, Value};
+use crate::{
+	sys::{napi_call_function, napi_create_symbol, napi_status},
+	Env, Error, Object, Result, String, Value,
+};
+use std::mem::MaybeUninit;
 
 pub struct Symbol<'a>(Value<'a>);
 
@@ -14,4 +18,88 @@ impl<'a> Symbol<'a> {
 	pub fn env(&self) -> Env<'a> {
 		self.0.env()
 	}
+
+	pub fn new(env: Env<'a>, description: Option<&str>) -> Result<Symbol<'a>> {
+		let description = match description {
+			Some(description) => String::new(env, description)?.value().raw(),
+			None => std::ptr::null_mut(),
+		};
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_create_symbol(env.raw(), description, result.as_mut_ptr());
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			result.assume_init()
+		};
+		let value = Value::from_raw(env, value);
+		let value = Symbol(value);
+		Ok(value)
+	}
+
+	/// Get the symbol registered under `key` in the global symbol registry, as `Symbol.for(key)` does.
+	pub fn for_key(env: Env<'a>, key: &str) -> Result<Symbol<'a>> {
+		let constructor = Symbol::constructor(env)?;
+		let function = constructor.get(String::new(env, "for")?)?;
+		let key = String::new(env, key)?;
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_call_function(
+				env.raw(),
+				constructor.value().raw(),
+				function.raw(),
+				1,
+				&key.value().raw(),
+				result.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			result.assume_init()
+		};
+		let value = Value::from_raw(env, value);
+		value.as_symbol()
+	}
+
+	pub fn iterator(env: Env<'a>) -> Result<Symbol<'a>> {
+		Symbol::well_known(env, "iterator")
+	}
+
+	pub fn async_iterator(env: Env<'a>) -> Result<Symbol<'a>> {
+		Symbol::well_known(env, "asyncIterator")
+	}
+
+	pub fn has_instance(env: Env<'a>) -> Result<Symbol<'a>> {
+		Symbol::well_known(env, "hasInstance")
+	}
+
+	pub fn to_primitive(env: Env<'a>) -> Result<Symbol<'a>> {
+		Symbol::well_known(env, "toPrimitive")
+	}
+
+	pub fn to_string_tag(env: Env<'a>) -> Result<Symbol<'a>> {
+		Symbol::well_known(env, "toStringTag")
+	}
+
+	pub fn description(&self) -> Result<Option<std::string::String>> {
+		let object = Object::from_value(self.value());
+		let description = object.get(String::new(self.env(), "description")?)?;
+		if description.is_undefined()? {
+			Ok(None)
+		} else {
+			Ok(Some(description.as_string()?.get()?))
+		}
+	}
+
+	fn constructor(env: Env<'a>) -> Result<Object<'a>> {
+		let global = env.global()?.as_object()?;
+		let constructor = global.get(String::new(env, "Symbol")?)?;
+		Ok(Object::from_value(constructor))
+	}
+
+	fn well_known(env: Env<'a>, name: &str) -> Result<Symbol<'a>> {
+		let constructor = Symbol::constructor(env)?;
+		let value = constructor.get(String::new(env, name)?)?;
+		value.as_symbol()
+	}
 }
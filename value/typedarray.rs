@@ -1,7 +1,130 @@
-use crate::{Env, Value};
+use crate::{
+	sys::{
+		napi_create_typedarray, napi_get_typedarray_info, napi_status, napi_typedarray_type,
+		napi_value,
+	},
+	ArrayBuffer, Env, Error, Result, Value,
+};
+use std::{ffi::c_void, mem::MaybeUninit};
 
 pub struct TypedArray<'a>(Value<'a>);
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TypedArrayType {
+	Int8,
+	Uint8,
+	Uint8Clamped,
+	Int16,
+	Uint16,
+	Int32,
+	Uint32,
+	Float32,
+	Float64,
+	BigInt64,
+	BigUint64,
+}
+
+impl TypedArrayType {
+	pub fn element_size(&self) -> usize {
+		match self {
+			TypedArrayType::Int8 | TypedArrayType::Uint8 | TypedArrayType::Uint8Clamped => 1,
+			TypedArrayType::Int16 | TypedArrayType::Uint16 => 2,
+			TypedArrayType::Int32 | TypedArrayType::Uint32 | TypedArrayType::Float32 => 4,
+			TypedArrayType::Float64 | TypedArrayType::BigInt64 | TypedArrayType::BigUint64 => 8,
+		}
+	}
+}
+
+impl From<napi_typedarray_type> for TypedArrayType {
+	fn from(value: napi_typedarray_type) -> TypedArrayType {
+		match value {
+			napi_typedarray_type::napi_int8_array => TypedArrayType::Int8,
+			napi_typedarray_type::napi_uint8_array => TypedArrayType::Uint8,
+			napi_typedarray_type::napi_uint8_clamped_array => TypedArrayType::Uint8Clamped,
+			napi_typedarray_type::napi_int16_array => TypedArrayType::Int16,
+			napi_typedarray_type::napi_uint16_array => TypedArrayType::Uint16,
+			napi_typedarray_type::napi_int32_array => TypedArrayType::Int32,
+			napi_typedarray_type::napi_uint32_array => TypedArrayType::Uint32,
+			napi_typedarray_type::napi_float32_array => TypedArrayType::Float32,
+			napi_typedarray_type::napi_float64_array => TypedArrayType::Float64,
+			napi_typedarray_type::napi_bigint64_array => TypedArrayType::BigInt64,
+			napi_typedarray_type::napi_biguint64_array => TypedArrayType::BigUint64,
+		}
+	}
+}
+
+impl From<TypedArrayType> for napi_typedarray_type {
+	fn from(value: TypedArrayType) -> napi_typedarray_type {
+		match value {
+			TypedArrayType::Int8 => napi_typedarray_type::napi_int8_array,
+			TypedArrayType::Uint8 => napi_typedarray_type::napi_uint8_array,
+			TypedArrayType::Uint8Clamped => napi_typedarray_type::napi_uint8_clamped_array,
+			TypedArrayType::Int16 => napi_typedarray_type::napi_int16_array,
+			TypedArrayType::Uint16 => napi_typedarray_type::napi_uint16_array,
+			TypedArrayType::Int32 => napi_typedarray_type::napi_int32_array,
+			TypedArrayType::Uint32 => napi_typedarray_type::napi_uint32_array,
+			TypedArrayType::Float32 => napi_typedarray_type::napi_float32_array,
+			TypedArrayType::Float64 => napi_typedarray_type::napi_float64_array,
+			TypedArrayType::BigInt64 => napi_typedarray_type::napi_bigint64_array,
+			TypedArrayType::BigUint64 => napi_typedarray_type::napi_biguint64_array,
+		}
+	}
+}
+
+/// A Rust type that can be used to view the elements of a `TypedArray`.
+/// # Safety
+/// Implementors must have the same size and representation as the elements of every `TypedArrayType` in `TYPES`.
+pub unsafe trait TypedArrayElement: Copy {
+	const TYPES: &'static [TypedArrayType];
+}
+
+unsafe impl TypedArrayElement for i8 {
+	const TYPES: &'static [TypedArrayType] = &[TypedArrayType::Int8];
+}
+
+unsafe impl TypedArrayElement for u8 {
+	const TYPES: &'static [TypedArrayType] = &[TypedArrayType::Uint8, TypedArrayType::Uint8Clamped];
+}
+
+unsafe impl TypedArrayElement for i16 {
+	const TYPES: &'static [TypedArrayType] = &[TypedArrayType::Int16];
+}
+
+unsafe impl TypedArrayElement for u16 {
+	const TYPES: &'static [TypedArrayType] = &[TypedArrayType::Uint16];
+}
+
+unsafe impl TypedArrayElement for i32 {
+	const TYPES: &'static [TypedArrayType] = &[TypedArrayType::Int32];
+}
+
+unsafe impl TypedArrayElement for u32 {
+	const TYPES: &'static [TypedArrayType] = &[TypedArrayType::Uint32];
+}
+
+unsafe impl TypedArrayElement for f32 {
+	const TYPES: &'static [TypedArrayType] = &[TypedArrayType::Float32];
+}
+
+unsafe impl TypedArrayElement for f64 {
+	const TYPES: &'static [TypedArrayType] = &[TypedArrayType::Float64];
+}
+
+unsafe impl TypedArrayElement for i64 {
+	const TYPES: &'static [TypedArrayType] = &[TypedArrayType::BigInt64];
+}
+
+unsafe impl TypedArrayElement for u64 {
+	const TYPES: &'static [TypedArrayType] = &[TypedArrayType::BigUint64];
+}
+
+pub struct TypedArrayInfo<'a> {
+	pub kind: TypedArrayType,
+	pub length: usize,
+	pub arraybuffer: ArrayBuffer<'a>,
+	pub byte_offset: usize,
+}
+
 impl<'a> TypedArray<'a> {
 	pub(crate) fn from_value(value: Value) -> TypedArray {
 		TypedArray(value)
@@ -14,4 +137,114 @@ impl<'a> TypedArray<'a> {
 	pub fn env(&self) -> Env<'a> {
 		self.0.env()
 	}
+
+	/// Create a `TypedArray` with `length` elements of type `kind` over `arraybuffer`, starting at `byte_offset`.
+	pub fn new(
+		env: Env<'a>,
+		kind: TypedArrayType,
+		length: usize,
+		arraybuffer: &ArrayBuffer<'a>,
+		byte_offset: usize,
+	) -> Result<TypedArray<'a>> {
+		let value = unsafe {
+			let mut result = MaybeUninit::uninit();
+			let status = napi_create_typedarray(
+				env.raw(),
+				kind.into(),
+				length,
+				arraybuffer.value().raw(),
+				byte_offset,
+				result.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(env.raw(), status));
+			}
+			result.assume_init()
+		};
+		let value = Value::from_raw(env, value);
+		let value = TypedArray(value);
+		Ok(value)
+	}
+
+	fn raw_info(&self) -> Result<(TypedArrayType, usize, *mut c_void, napi_value, usize)> {
+		unsafe {
+			let mut kind = MaybeUninit::uninit();
+			let mut length = MaybeUninit::uninit();
+			let mut data = MaybeUninit::uninit();
+			let mut arraybuffer = MaybeUninit::uninit();
+			let mut byte_offset = MaybeUninit::uninit();
+			let status = napi_get_typedarray_info(
+				self.env().raw(),
+				self.value().raw(),
+				kind.as_mut_ptr(),
+				length.as_mut_ptr(),
+				data.as_mut_ptr(),
+				arraybuffer.as_mut_ptr(),
+				byte_offset.as_mut_ptr(),
+			);
+			if status != napi_status::napi_ok {
+				return Err(Error::from_last_node_api_error(self.env().raw(), status));
+			}
+			Ok((
+				kind.assume_init().into(),
+				length.assume_init(),
+				data.assume_init(),
+				arraybuffer.assume_init(),
+				byte_offset.assume_init(),
+			))
+		}
+	}
+
+	pub fn info(&self) -> Result<TypedArrayInfo<'a>> {
+		let (kind, length, _, arraybuffer, byte_offset) = self.raw_info()?;
+		let arraybuffer = ArrayBuffer::from_value(Value::from_raw(self.env(), arraybuffer));
+		Ok(TypedArrayInfo {
+			kind,
+			length,
+			arraybuffer,
+			byte_offset,
+		})
+	}
+
+	pub fn kind(&self) -> Result<TypedArrayType> {
+		let (kind, _, _, _, _) = self.raw_info()?;
+		Ok(kind)
+	}
+
+	pub fn len(&self) -> Result<usize> {
+		let (_, length, _, _, _) = self.raw_info()?;
+		Ok(length)
+	}
+
+	pub fn is_empty(&self) -> Result<bool> {
+		Ok(self.len()? == 0)
+	}
+
+	fn typed_data<T: TypedArrayElement>(&self) -> Result<(*mut T, usize)> {
+		let (kind, length, data, _, _) = self.raw_info()?;
+		if !T::TYPES.contains(&kind) {
+			return Err(Error::message(
+				"typedarray element type does not match the requested type",
+			));
+		}
+		Ok((data as *mut T, length))
+	}
+
+	pub fn as_slice<T: TypedArrayElement>(&self) -> Result<&[T]> {
+		let (data, length) = self.typed_data::<T>()?;
+		if length == 0 {
+			return Ok(&[]);
+		}
+		let slice = unsafe { std::slice::from_raw_parts(data, length) };
+		Ok(slice)
+	}
+
+	pub fn as_mut_slice<T: TypedArrayElement>(&mut self) -> Result<&mut [T]> {
+		let (data, length) = self.typed_data::<T>()?;
+		if length == 0 {
+			return Ok(&mut []);
+		}
+		let slice = unsafe { std::slice::from_raw_parts_mut(data, length) };
+		Ok(slice)
+	}
 }